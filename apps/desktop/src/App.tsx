@@ -18,7 +18,11 @@ import { BrandName } from "./components/BrandName";
 import { TitleBar } from "./components/layout/TitleBar";
 import { Sidebar } from "./components/layout/Sidebar";
 import { DiscordSettings } from "./components/DiscordSettings";
+import { WebhookSettings } from "./components/WebhookSettings";
 import { ConsoleView } from "./components/ConsoleView";
+import { ServerLogsPanel } from "./components/ServerLogsPanel";
+import { RecentIssuesPanel } from "./components/RecentIssuesPanel";
+import { PlayerListsPanel } from "./components/PlayerListsPanel";
 import { CrashModal } from "./components/modals/CrashModal";
 import { DeleteServerModal } from "./components/modals/DeleteServerModal";
 import { ImportServerModal } from "./components/modals/ImportServerModal";
@@ -42,41 +46,80 @@ import { pickAndValidateMods } from "./services/modImport";
 import { detectServerMetadata } from "./services/modDetection";
 import { detectClient } from "./services/clientDetector";
 import { compareClientToServer } from "./services/versionComparator";
-import { launchMinecraft as launchMinecraftClient } from "./services/minecraftLauncher";
+import {
+  detectInstalledLaunchers,
+  launchMinecraft as launchMinecraftClient
+} from "./services/minecraftLauncher";
 import { ensureClientLoaderInstalled } from "./services/loaderInstaller";
 import { createLauncherProfile } from "./services/launcherProfileManager";
 import { resolveRequiredClient } from "./services/versionResolver";
 import { useServerMetadata } from "./hooks/useServerMetadata";
 import type {
+  AppConfigImport,
   AppSettings,
   ApplyResult,
+  AutoExportSettings,
+  AvailableCommand,
   BackupEntry,
+  BackupReconcileResult,
+  CommandHistoryEntry,
   CrashReport,
   CrashReportSummary,
+  ConsoleIssue,
+  AddModResult,
+  AppError,
+  DeleteServerPreview,
+  DeleteServerResult,
+  ForgeVersions,
+  SupportedVersions,
+  UuidMigrationResult,
   ImportAnalysis,
   JavaStatusResult,
+  InstalledLauncher,
   LauncherChoice,
   ClientDetectionResult,
+  ModDownloadResult,
   ModEntry,
   ModpackManifest,
   ModSyncStatus,
   ModsImportMode,
+  ModsImportResult,
+  ServerLogFile,
+  ServerLogMatch,
+  TempUsage,
   ModsValidationResult,
   NetworkInfo,
+  PendingChangesEvent,
+  PlayerImportResult,
+  PlayerModeration,
+  ProcessPriority,
+  RegionValidation,
   ResourceUsage,
   ServerConfig,
+  ServerFilesCheck,
+  ServerHeartbeat,
   ServerMeta,
+  ServerStats,
   ServerSettings,
   ServerStatus,
+  ServerStatusInfo,
+  SettingDrift,
+  StartupPhase,
   UpdateInfo,
   VersionGroup,
   View,
+  WatchdogAction,
   WorldCopyProgress,
+  ShutdownProgress,
+  StorageWarning,
+  TransferProgress,
   WorldImportMode,
   WorldImportPayload,
+  WorldInfo,
   WorldValidationResult
 } from "./types";
 import type { ConsoleEntry } from "./services/consoleParser";
+import { errorCode, errorMessage } from "./utils/errors";
 
 const isTauri = typeof window !== "undefined" && "__TAURI_INTERNALS__" in window;
 
@@ -187,6 +230,16 @@ const RAM_OPTIONS = [2, 4, 6, 8, 12];
 const MAX_VERSION_OPTIONS = 200;
 const MAX_VERSION_OPTIONS_FORGE = 5;
 const BACKUP_INTERVALS = [30, 60, 360, 1440] as const;
+const PROCESS_PRIORITIES: { value: ProcessPriority; label: string }[] = [
+  { value: "below_normal", label: "Below normal" },
+  { value: "normal", label: "Normal" },
+  { value: "high", label: "High" }
+];
+const WATCHDOG_ACTIONS: { value: WatchdogAction; label: string }[] = [
+  { value: "notify", label: "Notify" },
+  { value: "restart", label: "Restart" },
+  { value: "kill", label: "Kill" }
+];
 const UPDATE_REPO = "Adlikkk/gamehost-one-app";
 const UPDATE_SKIP_CRASH_KEY = "gho_skip_crash_modal_once";
 
@@ -249,14 +302,20 @@ const TUTORIAL_STEPS: TutorialStep[] = [
 
 const DEFAULT_SETTINGS: ServerSettings = {
   sleepPlayers: 1,
+  sleepPercentage: 5,
   difficulty: "Normal",
   gameMode: "Survival",
   pvp: true,
   allowFlight: false,
   maxPlayers: 20,
-  viewDistance: 10
+  viewDistance: 10,
+  simulationDistance: 10
 };
 
+const DEFAULT_AUTO_EXPORT: AutoExportSettings = { enabled: false, interval_hours: 24, keep_last_n: 7 };
+
+const AUTO_EXPORT_INTERVALS = [6, 12, 24, 168];
+
 const DEFAULT_SERVER_META: ServerMeta = {
   auto_backup: false,
   backup_interval_minutes: 60,
@@ -269,7 +328,16 @@ const DEFAULT_SERVER_META: ServerMeta = {
   discord_template_start: DEFAULT_DISCORD_TEMPLATES.start,
   discord_template_stop: DEFAULT_DISCORD_TEMPLATES.stop,
   discord_template_crash: DEFAULT_DISCORD_TEMPLATES.crash,
-  discord_template_ram: DEFAULT_DISCORD_TEMPLATES.ram
+  discord_template_ram: DEFAULT_DISCORD_TEMPLATES.ram,
+  backup_compression: "default",
+  incremental_backups: false,
+  full_backup_every: 12,
+  process_priority: "normal",
+  properties_authoritative: false,
+  watchdog_enabled: false,
+  watchdog_timeout_seconds: 120,
+  watchdog_action: "notify",
+  stop_grace_seconds: 10
 };
 
 const getDefaultVersion = (serverType: ServerConfig["server_type"]) => {
@@ -288,14 +356,19 @@ const container = {
 
 const item = { hidden: { opacity: 0, y: 16 }, show: { opacity: 1, y: 0 } };
 
-const getActionState = (status: ServerStatus) => {
+const startupLabel = (phase?: StartupPhase | null) => {
+  if (!phase) return "Starting server...";
+  return phase.percent != null ? `${phase.phase} ${phase.percent}%...` : `${phase.phase}...`;
+};
+
+const getActionState = (status: ServerStatus, phase?: StartupPhase | null) => {
   const starting = status === "STARTING";
   return {
     canStart: status === "STOPPED" || status === "ERROR",
     canStop: status === "RUNNING",
     canRestart: status === "RUNNING",
     showStarting: starting,
-    statusLabel: starting ? "Starting server..." : null
+    statusLabel: starting ? startupLabel(phase) : null
   };
 };
 
@@ -463,6 +536,24 @@ function buildForgeVersionList(versions: string[], selected: string | null, limi
   return limited;
 }
 
+const formatDuration = (seconds: number) => {
+  const hours = Math.floor(seconds / 3600);
+  const minutes = Math.floor((seconds % 3600) / 60);
+  if (hours > 0) return `${hours}h ${minutes}m`;
+  if (minutes > 0) return `${minutes}m`;
+  return `${seconds}s`;
+};
+
+const formatRunStats = (stats: ServerStats, uptimeSeconds: number | null) => {
+  const weekAgo = Date.now() - 7 * 24 * 60 * 60 * 1000;
+  const crashesThisWeek = stats.recent_crashes.filter((value) => Date.parse(value) >= weekAgo).length;
+  const parts = [
+    uptimeSeconds !== null ? `up ${formatDuration(uptimeSeconds)}` : `${stats.total_starts} starts`,
+    stats.last_startup_seconds != null ? `last start took ${stats.last_startup_seconds}s` : null,
+    `${crashesThisWeek} crash${crashesThisWeek === 1 ? "" : "es"} this week`
+  ];
+  return parts.filter(Boolean).join(" · ");
+};
 
 function App() {
   const [view, setView] = useState<View>("loading");
@@ -487,6 +578,7 @@ function App() {
   } | null>(null);
   const [launcherChoice, setLauncherChoice] = useState<LauncherChoice | null>(null);
   const [launcherChoiceOpen, setLauncherChoiceOpen] = useState(false);
+  const [installedLaunchers, setInstalledLaunchers] = useState<InstalledLauncher[]>([]);
   const [launcherOpenedAt, setLauncherOpenedAt] = useState<number | null>(null);
   const [consoleEntries, setConsoleEntries] = useState<ConsoleEntry[]>([]);
   const [installing, setInstalling] = useState(false);
@@ -501,9 +593,11 @@ function App() {
   const [updateChecking, setUpdateChecking] = useState(false);
   const [updateModalOpen, setUpdateModalOpen] = useState(false);
   const [updateDownloading, setUpdateDownloading] = useState(false);
+  const [updateDownloadPercent, setUpdateDownloadPercent] = useState<number | null>(null);
   const [updateError, setUpdateError] = useState<string | null>(null);
   const [discordModalOpen, setDiscordModalOpen] = useState(false);
   const [crashReports, setCrashReports] = useState<CrashReportSummary[]>([]);
+  const [tempUsage, setTempUsage] = useState<TempUsage | null>(null);
   const [crashModalOpen, setCrashModalOpen] = useState(false);
   const [activeCrashReport, setActiveCrashReport] = useState<CrashReport | null>(null);
   const [crashLoading, setCrashLoading] = useState(false);
@@ -532,6 +626,9 @@ function App() {
   const [wizardModsError, setWizardModsError] = useState<string | null>(null);
   const [wizardModsBusy, setWizardModsBusy] = useState(false);
   const [serverSettingsByName, setServerSettingsByName] = useState<Record<string, ServerSettings>>({});
+  const [pendingChangesByName, setPendingChangesByName] = useState<Record<string, string[]>>({});
+  const [worldImportBusy, setWorldImportBusy] = useState(false);
+  const [modsImportBusy, setModsImportBusy] = useState(false);
   const [isMaximized, setIsMaximized] = useState(false);
   const lastStatusRef = useRef<ServerStatus>("STOPPED");
   const welcomeShownRef = useRef(false);
@@ -546,16 +643,23 @@ function App() {
   const [ramManualInput, setRamManualInput] = useState("4");
   const [wizardRamAuto, setWizardRamAuto] = useState(true);
   const [onlineModeDraft, setOnlineModeDraft] = useState(true);
+  const [stopCommandDraft, setStopCommandDraft] = useState("stop");
+  const [stopGraceDraft, setStopGraceDraft] = useState(10);
+  const [portDraft, setPortDraft] = useState(25565);
   const [configSaving, setConfigSaving] = useState(false);
   const [deleteTarget, setDeleteTarget] = useState<ServerConfig | null>(null);
   const [deleteConfirm, setDeleteConfirm] = useState("");
   const [deleteBusy, setDeleteBusy] = useState(false);
+  const [deletePreview, setDeletePreview] = useState<DeleteServerPreview | null>(null);
+  const [deleteBackups, setDeleteBackups] = useState(false);
+  const [deleteMeta, setDeleteMeta] = useState(false);
   const [mods, setMods] = useState<ModEntry[]>([]);
   const [modsLoading, setModsLoading] = useState(false);
   const [modsBulkBusy, setModsBulkBusy] = useState(false);
   const [modpack, setModpack] = useState<ModpackManifest | null>(null);
   const [modSync, setModSync] = useState<ModSyncStatus | null>(null);
   const [modSyncLoading, setModSyncLoading] = useState(false);
+  const [modDownload, setModDownload] = useState<{ operationId: string; percent: number } | null>(null);
   const [modSyncModalOpen, setModSyncModalOpen] = useState(false);
   const [modsModalOpen, setModsModalOpen] = useState(false);
   const [modSyncChoiceOpen, setModSyncChoiceOpen] = useState(false);
@@ -591,7 +695,13 @@ function App() {
   const [exportProgress, setExportProgress] = useState<number | null>(null);
   const [backupIncludeNether, setBackupIncludeNether] = useState(true);
   const [backupIncludeEnd, setBackupIncludeEnd] = useState(true);
+  const [worlds, setWorlds] = useState<WorldInfo[]>([]);
+  const [backupExtraWorlds, setBackupExtraWorlds] = useState<string[]>([]);
+  const [backupPassword, setBackupPassword] = useState("");
+  const [startupPhase, setStartupPhase] = useState<StartupPhase | null>(null);
+  const [offline, setOffline] = useState(false);
   const [serverMeta, setServerMeta] = useState<ServerMeta | null>(null);
+  const [uptimeSeconds, setUptimeSeconds] = useState<number | null>(null);
   const ramAlertRef = useRef<number | null>(null);
   const ramWebhookRef = useRef(false);
   const lastChatWarnRef = useRef(0);
@@ -601,6 +711,7 @@ function App() {
   const [showTutorialOnStartup, setShowTutorialOnStartup] = useState(true);
   const [wizardVersionFilter, setWizardVersionFilter] = useState("");
   const [wizardForgeVersions, setWizardForgeVersions] = useState<string[]>([]);
+  const [wizardSupportedVersions, setWizardSupportedVersions] = useState<SupportedVersions | null>(null);
   const [wizardForgeLoading, setWizardForgeLoading] = useState(false);
   const [reinstallType, setReinstallType] = useState<ServerConfig["server_type"]>("vanilla");
   const [reinstallVersion, setReinstallVersion] = useState(getDefaultVersion("vanilla"));
@@ -608,6 +719,8 @@ function App() {
   const [reinstallForgeVersions, setReinstallForgeVersions] = useState<string[]>([]);
   const [reinstallForgeLoading, setReinstallForgeLoading] = useState(false);
   const [reinstallBusy, setReinstallBusy] = useState(false);
+  const [serverFilesBusy, setServerFilesBusy] = useState(false);
+  const [worldValidateProgress, setWorldValidateProgress] = useState<number | null>(null);
 
   const [wizardName, setWizardName] = useState("My Minecraft Server");
   const [wizardType, setWizardType] = useState<ServerConfig["server_type"]>("vanilla");
@@ -624,7 +737,7 @@ function App() {
   );
 
   const normalizedStatus = normalizeStatus(status);
-  const actionState = getActionState(normalizedStatus);
+  const actionState = getActionState(normalizedStatus, startupPhase);
   const systemRamGb = useMemo(() => {
     if (!systemRamMb) return null;
     const value = systemRamMb > 1024 * 1024 ? systemRamMb / (1024 * 1024) : systemRamMb / 1024;
@@ -707,7 +820,8 @@ function App() {
     launcher_path: null,
     smart_join_panel_enabled: true,
     notify_on_server_start: true,
-    mod_sync_mode: "ask"
+    mod_sync_mode: "ask",
+    port_check_url: null
   };
   const deferredWizardFilter = useDeferredValue(wizardVersionFilter);
   const deferredReinstallFilter = useDeferredValue(reinstallVersionFilter);
@@ -721,8 +835,22 @@ function App() {
       const list = buildForgeVersionList(wizardForgeVersions, wizardVersion, MAX_VERSION_OPTIONS_FORGE);
       return [{ label: "All Versions", versions: list.map((value) => ({ value })) }];
     }
+    if (wizardType !== "forge" && wizardSupportedVersions && wizardSupportedVersions.versions.length > 0) {
+      const stable = wizardSupportedVersions.versions.filter((version) => !version.experimental);
+      const experimental = wizardSupportedVersions.versions.filter((version) => version.experimental);
+      return [
+        {
+          label: wizardSupportedVersions.stale ? "Supported (offline copy)" : "Supported",
+          versions: stable.map((version, index) => ({ value: version.id, recommended: index === 0 }))
+        },
+        {
+          label: "Experimental",
+          versions: experimental.map((version) => ({ value: version.id, label: `${version.id} (experimental)` }))
+        }
+      ].filter((group) => group.versions.length > 0);
+    }
     return VERSION_OPTIONS[wizardType];
-  }, [wizardType, wizardForgeVersions, wizardVersion]);
+  }, [wizardType, wizardForgeVersions, wizardSupportedVersions, wizardVersion]);
 
   const wizardVersionLimit = wizardType === "forge" ? MAX_VERSION_OPTIONS_FORGE : MAX_VERSION_OPTIONS;
   const wizardFilteredVersionGroups = useMemo<VersionGroup[]>(
@@ -761,6 +889,22 @@ function App() {
 
   const updateWizardSettings = (next: ServerSettings) => setWizardSettings(next);
 
+  useEffect(() => {
+    setDeletePreview(null);
+    setDeleteBackups(false);
+    setDeleteMeta(false);
+    if (!deleteTarget || !isTauri) return;
+    let cancelled = false;
+    invoke<DeleteServerPreview>("preview_delete_server", { serverId: deleteTarget.name })
+      .then((preview) => {
+        if (!cancelled) setDeletePreview(preview);
+      })
+      .catch(() => undefined);
+    return () => {
+      cancelled = true;
+    };
+  }, [deleteTarget]);
+
   useEffect(() => {
     const handleError = (event: ErrorEvent) => setFatalError(event.message || "Unexpected error");
     const handleRejection = (event: PromiseRejectionEvent) =>
@@ -800,8 +944,9 @@ function App() {
         const active = await invoke<string | null>("get_active_server_id");
         setActiveServerId(active);
         if (active) {
-          const current = await invoke<ServerStatus>("get_status", { serverId: active });
-          setStatus(normalizeStatus(String(current)));
+          const current = await invoke<ServerStatusInfo>("get_status", { serverId: active });
+          setStatus(normalizeStatus(String(current.status)));
+          setStartupPhase(current.phase ? { phase: current.phase, percent: current.percent ?? null } : null);
         } else {
           setStatus("STOPPED");
         }
@@ -809,7 +954,7 @@ function App() {
         setStatus("STOPPED");
       }
 
-      await Promise.all([refreshNetwork(), loadAppSettings(), loadCrashReports(), handleCheckUpdates(true)]);
+      await Promise.all([refreshNetwork(), loadAppSettings(), loadCrashReports(), loadTempUsage(), handleCheckUpdates(true)]);
     };
 
     init().finally(() => setView("library"));
@@ -843,20 +988,106 @@ function App() {
           });
         }
       }),
-      listen("server:start", () => setStatus("STARTING")),
-      listen("server:ready", () => setStatus("RUNNING")),
-      listen("server:error", () => setStatus("ERROR")),
+      listen("server:start", () => {
+        setStartupPhase(null);
+        setStatus("STARTING");
+      }),
+      listen<StartupPhase>("server:startup_phase", (event) => setStartupPhase(event.payload)),
+      listen<PlayerModeration>("player:moderated", (event) => {
+        const { player, action, reason } = event.payload;
+        setUiToast({
+          tone: "success",
+          message: `${player} was ${action === "ban" ? "banned" : "kicked"}${reason ? `: ${reason}` : "."}`
+        });
+      }),
+      listen<{ startup_ms: number | null }>("server:ready", () => {
+        setStartupPhase(null);
+        setStatus("RUNNING");
+      }),
+      listen<AppError | null>("server:error", (event) => {
+        setStatus("ERROR");
+        if (event.payload) {
+          setUiToast({ tone: "error", message: errorMessage(event.payload) });
+        }
+      }),
       listen("server:stopped", () => setStatus("STOPPED")),
-      listen<{ server_id: string; progress: number }>("backup:progress", (event) => {
-        setBackupProgress(event.payload.progress);
+      listen<{ server_id: string }>("server:previous_unclean_shutdown", (event) => {
+        setUiToast({
+          tone: "error",
+          message: `${event.payload.server_id} did not shut down cleanly last time. If the world looks wrong, stop it and run Validate world.`
+        });
+      }),
+      listen<{ server_id: string; warnings: StorageWarning[] }>("server:storage_warning", (event) => {
+        const [first, ...rest] = event.payload.warnings;
+        if (!first) return;
+        setUiToast({
+          tone: "error",
+          label: "Storage warning",
+          message: `${first.message} ${first.remediation}${rest.length > 0 ? ` (+${rest.length} more)` : ""}`
+        });
+      }),
+      listen<{ server_id: string; error: string }>("export:failed", async (event) => {
+        const message = `Scheduled export of ${event.payload.server_id} failed: ${event.payload.error}`;
+        setUiToast({ tone: "error", message });
+        if (await isPermissionGranted()) {
+          sendNotification({ title: "World export failed", body: message });
+        }
+      }),
+      listen<{ server_id: string; silent_seconds: number; action: WatchdogAction }>("server:unresponsive", (event) => {
+        const { server_id, silent_seconds, action } = event.payload;
+        const outcome = action === "restart" ? " Restarting it." : action === "kill" ? " It was stopped." : "";
+        setUiToast({
+          tone: "error",
+          message: `${server_id} has not responded for ${silent_seconds} seconds.${outcome}`
+        });
       }),
-      listen<{ server_id: string; progress: number }>("export:progress", (event) => {
-        setExportProgress(event.payload.progress);
+      listen<{ server_id: string; entries: SettingDrift[] }>("settings:drift_detected", (event) => {
+        const keys = event.payload.entries.map((entry) => entry.key).join(", ");
+        setUiToast({
+          tone: "error",
+          message: `server.properties was edited outside the app (${keys}). Your saved settings were applied instead.`
+        });
+      }),
+      listen("network:offline", () => setOffline(true)),
+      listen("network:online", () => setOffline(false)),
+      listen<{ previous_ip: string; public_ip: string }>("network:changed", (event) => {
+        setNetwork((prev) => (prev ? { ...prev, public_ip: event.payload.public_ip } : prev));
+        setUiToast({
+          tone: "error",
+          message: `Your public IP changed to ${event.payload.public_ip}. Share the new address with your friends.`
+        });
+      }),
+      listen<TransferProgress & { overall_percent?: number }>("modsync:progress", (event) => {
+        const percent = event.payload.overall_percent;
+        if (percent == null) return;
+        setModDownload((prev) => (prev ? { ...prev, percent } : prev));
       }),
-      listen<number>("java:download", (event) => {
-        const value = Math.max(0, Math.min(100, Number(event.payload)));
+      listen<TransferProgress>("backup:progress", (event) => {
+        setBackupProgress(event.payload.percent);
+      }),
+      listen<TransferProgress>("export:progress", (event) => {
+        setExportProgress(event.payload.percent);
+      }),
+      listen<TransferProgress>("world:validate", (event) => {
+        setWorldValidateProgress(event.payload.percent);
+      }),
+      listen<TransferProgress>("java:download", (event) => {
+        const value = Math.max(0, Math.min(100, Math.round(event.payload.percent)));
         setJavaDownloadProgress(value);
       }),
+      listen<TransferProgress>("update:download", (event) => {
+        const { transferred_bytes, total_bytes } = event.payload;
+        setUpdateDownloadPercent(
+          total_bytes > 0 ? Math.min(100, Math.round((transferred_bytes / total_bytes) * 100)) : null
+        );
+      }),
+      listen<ShutdownProgress>("app:shutdown_progress", (event) => {
+        setUiToast({ tone: "success", message: event.payload.message });
+      }),
+      listen<PendingChangesEvent>("server:pending_changes", (event) => {
+        const { server_id, pending_changes } = event.payload;
+        setPendingChangesByName((prev) => ({ ...prev, [server_id]: pending_changes }));
+      }),
       listen<WorldCopyProgress>("world:copy", (event) => {
         setWizardWorldCopy(event.payload);
         if (event.payload.percent >= 100) {
@@ -908,22 +1139,17 @@ function App() {
 
   useEffect(() => {
     if (!isTauri) return;
-
-    const interval = setInterval(async () => {
-      try {
-        if (status === "RUNNING" && activeServerId) {
-          const usage = await invoke<ResourceUsage>("get_resource_usage", { serverId: activeServerId });
-          setResource(usage);
-        } else {
-          setResource(null);
-        }
-      } catch {
-        setResource(null);
-      }
-    }, 2000);
-
-    return () => clearInterval(interval);
-  }, [status, activeServerId]);
+    invoke("set_heartbeat", { enabled: true, intervalMs: 2000 }).catch(() => null);
+    const unlisten = listen<ServerHeartbeat[]>("server:heartbeat", (event) => {
+      const active = event.payload.find((entry) => entry.pid !== null && entry.pid !== undefined);
+      setResource(active?.status === "RUNNING" ? active.resource ?? null : null);
+      setUptimeSeconds(active?.uptime_seconds ?? null);
+    });
+    return () => {
+      unlisten.then((stop) => stop());
+      invoke("set_heartbeat", { enabled: false }).catch(() => null);
+    };
+  }, []);
 
   useEffect(() => {
     if (!activeServerId) {
@@ -934,10 +1160,16 @@ function App() {
   useEffect(() => {
     if (view !== "detail") return;
     refreshClientStatus();
-    const interval = setInterval(() => {
-      refreshClientStatus();
-    }, 5000);
-    return () => clearInterval(interval);
+    if (!isTauri) return;
+    invoke("start_client_watcher").catch(() => null);
+    const unlistenPromise = Promise.all([
+      listen("client:detected", () => refreshClientStatus()),
+      listen("client:closed", () => refreshClientStatus())
+    ]);
+    return () => {
+      unlistenPromise.then((callbacks) => callbacks.forEach((unlisten) => unlisten()));
+      invoke("stop_client_watcher").catch(() => null);
+    };
   }, [view]);
 
   useEffect(() => {
@@ -964,13 +1196,19 @@ function App() {
     setWizardVersionFilter("");
     if (wizardType !== "forge") {
       setWizardForgeVersions([]);
+      setWizardSupportedVersions(null);
       setWizardVersion(getDefaultVersion(wizardType));
+      if (wizardType === "vanilla" || wizardType === "paper") {
+        invoke<SupportedVersions>("get_supported_versions", { serverType: wizardType })
+          .then(setWizardSupportedVersions)
+          .catch(() => setWizardSupportedVersions(null));
+      }
       return;
     }
 
     setWizardForgeLoading(true);
-    invoke<string[]>("get_forge_versions")
-      .then((versions) => {
+    invoke<ForgeVersions>("get_forge_versions")
+      .then(({ versions }) => {
         setWizardForgeVersions(versions);
         if (versions.length === 0) return;
         const detected = wizardWorldDetected.type === "forge" ? wizardWorldDetected.version : null;
@@ -1052,8 +1290,8 @@ function App() {
       selectedServer && selectedServer.server_type === "forge" ? selectedServer.version : null;
 
     setReinstallForgeLoading(true);
-    invoke<string[]>("get_forge_versions")
-      .then((versions) => {
+    invoke<ForgeVersions>("get_forge_versions")
+      .then(({ versions }) => {
         setReinstallForgeVersions(versions);
         if (versions.length > 0) {
           const next = preferredVersion && versions.includes(preferredVersion) ? preferredVersion : versions[0];
@@ -1076,6 +1314,11 @@ function App() {
     loadModpack(selectedServer);
     refreshModSync(selectedServer);
     refreshClientStatus();
+    if (isTauri) {
+      invoke<string[]>("get_pending_changes", { serverId: selectedServer.name })
+        .then((pending) => setPendingChangesByName((prev) => ({ ...prev, [selectedServer.name]: pending })))
+        .catch(() => undefined);
+    }
   }, [selectedServer]);
 
   useEffect(() => {
@@ -1083,6 +1326,8 @@ function App() {
     setRamDraft(selectedServer.ram_gb);
     setRamManualInput(String(selectedServer.ram_gb));
     setOnlineModeDraft(selectedServer.online_mode);
+    setStopCommandDraft(selectedServer.stop_command ?? "stop");
+    setPortDraft(selectedServer.port);
     setReinstallType(selectedServer.server_type === "fabric" ? "vanilla" : selectedServer.server_type);
     setReinstallVersion(selectedServer.version);
     setReinstallVersionFilter("");
@@ -1126,7 +1371,13 @@ function App() {
 
   useEffect(() => {
     const stored = window.localStorage.getItem("gho_launcher_choice");
-    if (stored === "official" || stored === "tlauncher") {
+    if (
+      stored === "official" ||
+      stored === "tlauncher" ||
+      stored === "prism" ||
+      stored === "multimc" ||
+      stored === "curseforge"
+    ) {
       setLauncherChoice(stored);
       return;
     }
@@ -1140,6 +1391,13 @@ function App() {
     window.localStorage.setItem("gho_launcher_choice", launcherChoice);
   }, [launcherChoice]);
 
+  useEffect(() => {
+    if (!isTauri || !launcherChoiceOpen) return;
+    detectInstalledLaunchers()
+      .then(setInstalledLaunchers)
+      .catch(() => setInstalledLaunchers([]));
+  }, [launcherChoiceOpen]);
+
   useEffect(() => {
     if (clientStatus?.running) {
       setLauncherOpenedAt(null);
@@ -1454,14 +1712,14 @@ function App() {
     setWizardWorldBusy(true);
     setWizardWorldError(null);
     try {
-      const result = await pickAndValidateWorld(kind);
+      const result = await pickAndValidateWorld(kind, wizardVersion);
       if (!result) return;
       setWizardWorldSource(result.sourcePath);
       setWizardWorldValidation(result.validation);
       setWizardWorldCopy(null);
       setWizardWorldCopied(false);
     } catch (err) {
-      setWizardWorldError(String(err));
+      setWizardWorldError(errorMessage(err));
       setWizardWorldValidation(null);
     } finally {
       setWizardWorldBusy(false);
@@ -1485,7 +1743,7 @@ function App() {
       setWizardModsSource(result.sourcePath);
       setWizardModsValidation(result.validation);
     } catch (err) {
-      setWizardModsError(String(err));
+      setWizardModsError(errorMessage(err));
       setWizardModsValidation(null);
     } finally {
       setWizardModsBusy(false);
@@ -1525,6 +1783,7 @@ function App() {
               staged_path: wizardModsValidation.staged_path ?? null
             }
           : null;
+      const port = await invoke<number>("suggest_free_port", { startFrom: 25565 });
       const created = await invoke<ServerConfig>("create_server", {
         config: {
           name: wizardName.trim(),
@@ -1532,7 +1791,7 @@ function App() {
           version: wizardVersion,
           ramGb: wizardRam,
           onlineMode: wizardOnlineMode,
-          port: 25565,
+          port,
           worldImport: worldImportPayload,
           modImport: modImportPayload
         }
@@ -1552,7 +1811,7 @@ function App() {
         setWizardWorldCopied(true);
       }
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setError(message);
       setUiToast({ tone: "error", message });
     } finally {
@@ -1565,6 +1824,7 @@ function App() {
       throw new Error("Tauri runtime is not available.");
     }
 
+    const port = await invoke<number>("suggest_free_port", { startFrom: 25565 });
     const created = await invoke<ServerConfig>("create_server", {
       config: {
         name: payload.name,
@@ -1572,7 +1832,7 @@ function App() {
         version: payload.version,
         ramGb: payload.ramGb,
         onlineMode: payload.onlineMode,
-        port: 25565,
+        port,
         worldImport: payload.worldImport,
         modImport: payload.modImport ?? null
       }
@@ -1601,32 +1861,48 @@ function App() {
     try {
       await invoke("send_console_command", { serverId: selectedServer.name, command });
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setError(message);
       setUiToast({ tone: "error", message });
     }
   };
 
-  const refreshNetwork = async () => {
+  const refreshNetwork = async (forceRefresh = false) => {
     if (!isTauri) return;
     try {
       const port = selectedServer?.port ?? 25565;
-      const info = await invoke<NetworkInfo>("get_network_info", { port });
+      const info = await invoke<NetworkInfo>("get_network_info", {
+        port,
+        serverId: selectedServer?.name ?? null,
+        forceRefresh
+      });
       setNetwork(info);
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setError(message);
       setUiToast({ tone: "error", message });
     }
   };
 
+  const invokeWithMemoryOverride = async (command: "start_server" | "restart_server", server: ServerConfig) => {
+    try {
+      await invoke(command, { serverId: server.name });
+    } catch (err) {
+      const message = errorMessage(err);
+      if (!message.startsWith("Not enough free memory")) throw err;
+      const ok = await confirm(`${message}\n\nStart anyway?`, { title: "Low memory" });
+      if (!ok) throw err;
+      await invoke(command, { serverId: server.name, force: true });
+    }
+  };
+
   const runServerAction = async (action: "start" | "stop" | "restart", server: ServerConfig) => {
     if (action === "start") {
       if (activeServerId && activeServerId !== server.name) {
         setUiToast({ tone: "error", message: "Only one server can run at a time." });
         return;
       }
-      await invoke("start_server", { serverId: server.name });
+      await invokeWithMemoryOverride("start_server", server);
       setActiveServerId(server.name);
       return;
     }
@@ -1636,7 +1912,7 @@ function App() {
         setUiToast({ tone: "error", message: "Only one server can run at a time." });
         return;
       }
-      await invoke("restart_server", { serverId: server.name });
+      await invokeWithMemoryOverride("restart_server", server);
       setActiveServerId(server.name);
       return;
     }
@@ -1645,8 +1921,26 @@ function App() {
     setActiveServerId(null);
   };
 
+  const handleKillServer = async () => {
+    if (!isTauri || !selectedServer) return;
+    const ok = await confirm(
+      "Kill the server immediately? It will not save, and the world may need checking afterwards.",
+      { title: "Force kill", kind: "warning" }
+    );
+    if (!ok) return;
+    try {
+      await invoke("kill_server", { serverId: selectedServer.name });
+      setActiveServerId(null);
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
   const ensureJavaAndRun = async (action: "start" | "restart", server: ServerConfig) => {
-    const result = await invoke<JavaStatusResult>("check_java", { serverVersion: server.version });
+    const result = await invoke<JavaStatusResult>("check_java", {
+      serverVersion: server.version,
+      ramGb: server.ram_gb
+    });
     setJavaStatus(result);
     if (result.status === "ready") {
       await runServerAction(action, server);
@@ -1668,7 +1962,7 @@ function App() {
       }
       await runServerAction(action, server);
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setError(message);
       setUiToast({ tone: "error", message });
     }
@@ -1684,7 +1978,7 @@ function App() {
       );
       setLauncherOpenedAt(Date.now());
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -1718,7 +2012,7 @@ function App() {
           }
         }
       } catch (err) {
-        setModSyncChoiceError(String(err));
+        setModSyncChoiceError(errorMessage(err));
         setModSyncChoiceOpen(true);
         return;
       }
@@ -1772,14 +2066,30 @@ function App() {
       return;
     }
     setModSyncLoading(true);
+    const operationId = `modsync-${Date.now()}`;
+    setModDownload({ operationId, percent: 0 });
     try {
-      await invoke("download_mods", { serverId: selectedServer.name, modIds: missingIds });
+      const result = await invoke<ModDownloadResult>("download_mods", {
+        serverId: selectedServer.name,
+        modIds: missingIds,
+        operationId
+      });
       await refreshModSync(selectedServer);
-      setUiToast({ tone: "success", message: "Mods downloaded." });
+      if (result.cancelled) {
+        setUiToast({ tone: "error", message: `Download cancelled after ${result.downloaded.length} mods.` });
+      } else if (result.failed.length) {
+        setUiToast({
+          tone: "error",
+          message: `${result.failed.length} mods failed: ${result.failed.map((item) => `${item.id} (${item.error})`).join(", ")}`
+        });
+      } else {
+        setUiToast({ tone: "success", message: "Mods downloaded." });
+      }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setModSyncLoading(false);
+      setModDownload(null);
     }
   };
 
@@ -1819,7 +2129,7 @@ function App() {
         await launchMinecraftClient(launcherChoice, installed.versionId, server.name);
         setLauncherOpenedAt(Date.now());
       } catch (err) {
-        setLoaderInstallError(String(err));
+        setLoaderInstallError(errorMessage(err));
         setLoaderInstallBusy(false);
         return;
       }
@@ -1849,7 +2159,7 @@ function App() {
         await launchMinecraftClient(launcherChoice, installed.versionId, selectedServer.name);
         setLauncherOpenedAt(Date.now());
       } catch (err) {
-        setLoaderInstallError(String(err));
+        setLoaderInstallError(errorMessage(err));
         setLoaderInstallBusy(false);
         return;
       }
@@ -1871,11 +2181,19 @@ function App() {
 
   const handleInviteFriends = async () => {
     if (!selectedServer) return;
-    const address = await resolveServerAddress(selectedServer);
+    let address: string;
     try {
-      await navigator.clipboard?.writeText(address);
+      address = await invoke<string>("copy_connection_address", {
+        serverId: selectedServer.name,
+        kind: "recommended"
+      });
     } catch {
-      // Ignore clipboard errors and still show helper.
+      address = await resolveServerAddress(selectedServer);
+      try {
+        await navigator.clipboard?.writeText(address);
+      } catch {
+        // Ignore clipboard errors and still show helper.
+      }
     }
     setUiToast({ tone: "success", message: "Server IP copied to clipboard" });
     setJoinIp(address);
@@ -1885,9 +2203,9 @@ function App() {
   const resolveServerAddress = async (server: ServerConfig) => {
     let ip = network?.local_ip ?? "127.0.0.1";
     try {
-      const info = await invoke<NetworkInfo>("get_network_info", { port: server.port });
+      const info = await invoke<NetworkInfo>("get_network_info", { port: server.port, serverId: server.name });
       setNetwork(info);
-      ip = info.local_ip;
+      ip = info.local_ip ?? ip;
     } catch {
       // Ignore and fall back to existing IP.
     }
@@ -1900,7 +2218,7 @@ function App() {
     try {
       await openUrl(url);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -1915,7 +2233,7 @@ function App() {
         await openPath(minecraftDir);
       }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -1924,13 +2242,14 @@ function App() {
     await refreshModSync(selectedServer);
   };
 
-  const handleDownloadJava = async () => {
+  const handleDownloadJava = async (imageType: "jre" | "jdk") => {
     if (!isTauri || !pendingJavaAction) return;
     setJavaBusy(true);
     setJavaDownloadProgress(0);
     try {
       const result = await invoke<JavaStatusResult>("download_java", {
-        serverVersion: pendingJavaAction.server.version
+        serverVersion: pendingJavaAction.server.version,
+        imageType
       });
       setJavaStatus(result);
       if (result.status === "ready") {
@@ -1939,7 +2258,7 @@ function App() {
         setPendingJavaAction(null);
       }
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setUiToast({ tone: "error", message });
     } finally {
       setJavaBusy(false);
@@ -1959,7 +2278,8 @@ function App() {
     try {
       const result = await invoke<JavaStatusResult>("set_java_path", {
         javaPath: selection,
-        serverVersion: pendingJavaAction.server.version
+        serverVersion: pendingJavaAction.server.version,
+        ramGb: pendingJavaAction.server.ram_gb
       });
       setJavaStatus(result);
       if (result.status === "ready") {
@@ -1968,7 +2288,7 @@ function App() {
         setPendingJavaAction(null);
       }
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setUiToast({ tone: "error", message });
     } finally {
       setJavaBusy(false);
@@ -1979,31 +2299,56 @@ function App() {
     if (!selectedServer || !isTauri) return;
     setConfigSaving(true);
     try {
-      const result = await invoke<ApplyResult>("update_server_config", {
-        payload: {
-          serverId: selectedServer.name,
-          ramGb: ramDraft,
-          onlineMode: onlineModeDraft
-        }
-      });
+      const payload = {
+        serverId: selectedServer.name,
+        ramGb: ramDraft,
+        onlineMode: onlineModeDraft,
+        stopCommand: stopCommandDraft,
+        stopGraceSeconds: stopGraceDraft,
+        port: portDraft
+      };
+      let result: ApplyResult;
+      let uuidChangeConfirmed = false;
+      try {
+        result = await invoke<ApplyResult>("update_server_config", { payload });
+      } catch (err) {
+        if (errorCode(err) !== "online_mode_uuid_change") throw err;
+        const ok = await confirm(errorMessage(err), { title: "Player data", kind: "warning" });
+        if (!ok) return;
+        result = await invoke<ApplyResult>("update_server_config", {
+          payload: { ...payload, confirmUuidChange: true }
+        });
+        uuidChangeConfirmed = true;
+      }
+      const stopCommand = stopCommandDraft.trim().replace(/^\/+/, "") || null;
+      const applied = { ram_gb: ramDraft, online_mode: onlineModeDraft, stop_command: stopCommand, port: portDraft };
       setServers((prev) =>
-        prev.map((server) =>
-          server.name === selectedServer.name
-            ? { ...server, ram_gb: ramDraft, online_mode: onlineModeDraft }
-            : server
-        )
-      );
-      setSelectedServer((prev) =>
-        prev ? { ...prev, ram_gb: ramDraft, online_mode: onlineModeDraft } : prev
+        prev.map((server) => (server.name === selectedServer.name ? { ...server, ...applied } : server))
       );
+      setSelectedServer((prev) => (prev ? { ...prev, ...applied } : prev));
+      setServerMeta((prev) => (prev ? { ...prev, stop_grace_seconds: stopGraceDraft } : prev));
 
       if (result.pending_restart) {
         setUiToast({ tone: "success", message: "Settings saved. Restart required." });
       } else {
         setUiToast({ tone: "success", message: "Settings applied." });
       }
+      if (uuidChangeConfirmed) {
+        const migrate = await confirm(
+          "Move existing player files to the new UUIDs now? The server must be stopped, and a backup is taken first.",
+          { title: "Migrate player UUIDs" }
+        );
+        if (migrate) {
+          const migration = await invoke<UuidMigrationResult>("migrate_player_uuids", {
+            serverId: selectedServer.name,
+            direction: onlineModeDraft ? "offline_to_online" : "online_to_offline"
+          });
+          const skipped = migration.skipped.length ? ` ${migration.skipped.length} skipped.` : "";
+          setUiToast({ tone: "success", message: `Migrated ${migration.migrated.length} players.${skipped}` });
+        }
+      }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setConfigSaving(false);
     }
@@ -2047,26 +2392,94 @@ function App() {
         await runServerAction("start", selectedServer);
       }
 
-      if (result.pending_restart && !isRunning) {
+      if (result.warnings?.length) {
+        setUiToast({ tone: "error", message: `RAM saved. ${result.warnings[0]}` });
+      } else if (result.pending_restart && !isRunning) {
         setUiToast({ tone: "success", message: "RAM saved. Restart required." });
       } else {
         setUiToast({ tone: "success", message: "RAM applied." });
       }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setConfigSaving(false);
     }
   };
 
+  const handleVerifyServerFiles = async (repair: boolean) => {
+    if (!selectedServer || !isTauri) return;
+    setServerFilesBusy(true);
+    try {
+      const check = await invoke<ServerFilesCheck>(repair ? "repair_server_jar" : "verify_server_files", {
+        serverId: selectedServer.name
+      });
+      if (check.problems.length) {
+        setUiToast({ tone: "error", message: `${check.problems.join(" · ")}. Try Repair files.` });
+      } else {
+        setUiToast({ tone: "success", message: repair ? "Server files repaired." : "Server files look good." });
+      }
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    } finally {
+      setServerFilesBusy(false);
+    }
+  };
+
+  const handleValidateWorld = async () => {
+    if (!selectedServer || !isTauri) return;
+    setWorldValidateProgress(0);
+    try {
+      let result = await invoke<RegionValidation>("validate_world", { serverId: selectedServer.name });
+      if (!result.suspect.length) {
+        setUiToast({ tone: "success", message: `Checked ${result.checked} region files. No problems found.` });
+        return;
+      }
+      const restore = await confirm(
+        `${result.suspect.length} of ${result.checked} region files look damaged:\n${result.suspect
+          .slice(0, 5)
+          .map((region) => `${region.path}: ${region.reason}`)
+          .join("\n")}\n\nRestore just these files from the latest backup?`,
+        { title: "Damaged region files", kind: "warning" }
+      );
+      if (!restore) {
+        setUiToast({ tone: "error", message: `${result.suspect.length} region files look damaged.` });
+        return;
+      }
+      result = await invoke<RegionValidation>("validate_world", { serverId: selectedServer.name, restore: true });
+      const restored = result.suspect.filter((region) => region.restored).length;
+      setUiToast({
+        tone: restored === result.suspect.length ? "success" : "error",
+        message: `Restored ${restored} of ${result.suspect.length} damaged region files from backup ${result.restored_from}.`
+      });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    } finally {
+      setWorldValidateProgress(null);
+    }
+  };
+
   const handleReinstallServer = async () => {
     if (!selectedServer || !isTauri) return;
+    if (selectedServer.linked) {
+      let ok = false;
+      try {
+        ok = await confirm(
+          `This server is linked to ${selectedServer.server_dir}. Reinstalling replaces everything in that folder except the world. Continue?`,
+          { title: "Reinstall linked server" }
+        );
+      } catch (err) {
+        setUiToast({ tone: "error", message: errorMessage(err) });
+        return;
+      }
+      if (!ok) return;
+    }
     setReinstallBusy(true);
     try {
       const updated = await invoke<ServerConfig>("reinstall_server", {
         serverId: selectedServer.name,
         serverType: reinstallType,
-        version: reinstallVersion
+        version: reinstallVersion,
+        allowDestructiveOnLinked: selectedServer.linked ?? false
       });
       setServers((prev) =>
         prev.map((server) => (server.name === selectedServer.name ? updated : server))
@@ -2079,12 +2492,24 @@ function App() {
       setUiToast({ tone: "success", message: "Server reinstalled." });
       await loadMods(updated);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setReinstallBusy(false);
     }
   };
 
+  // Extra worlds switch backups and exports to an explicit folder list; without them the
+  // Nether/End toggles apply as before.
+  const backupWorldSelection = () => {
+    if (backupExtraWorlds.length === 0) return null;
+    const standard = worlds
+      .filter((world) => world.standard)
+      .filter((world) => !world.name.endsWith("_nether") || backupIncludeNether)
+      .filter((world) => !world.name.endsWith("_the_end") || backupIncludeEnd)
+      .map((world) => world.name);
+    return [...standard, ...backupExtraWorlds];
+  };
+
   const handleCreateBackup = async () => {
     if (!selectedServer || !isTauri) return;
     setBackupProgress(0);
@@ -2093,12 +2518,14 @@ function App() {
         serverId: selectedServer.name,
         includeNether: backupIncludeNether,
         includeEnd: backupIncludeEnd,
-        reason: "manual"
+        worlds: backupWorldSelection(),
+        reason: "manual",
+        password: backupPassword || null
       });
       await loadBackups(selectedServer);
       setUiToast({ tone: "success", message: "Backup created." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setBackupProgress(null);
     }
@@ -2117,16 +2544,84 @@ function App() {
         serverId: selectedServer.name,
         destination,
         includeNether: backupIncludeNether,
-        includeEnd: backupIncludeEnd
+        includeEnd: backupIncludeEnd,
+        worlds: backupWorldSelection(),
+        password: backupPassword || null
       });
       setUiToast({ tone: "success", message: "World exported." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setExportProgress(null);
     }
   };
 
+  const handleImportWorld = async (kind: "folder" | "zip") => {
+    if (!selectedServer || !isTauri) return;
+    try {
+      const result = await pickAndValidateWorld(kind, selectedServer.version);
+      if (!result) return;
+      if (result.validation.compatibility === "downgrade_blocked") {
+        setUiToast({ tone: "error", message: result.validation.compatibility_message ?? "World is too new." });
+        return;
+      }
+      const ok = await confirm(
+        `Replace the world of ${selectedServer.name} with ${result.validation.world_name}? A backup of the current world is taken first.${
+          result.validation.compatibility === "upgrade_needed" ? `\n\n${result.validation.compatibility_message}` : ""
+        }`,
+        { title: "Import world" }
+      );
+      if (!ok) return;
+      setWorldImportBusy(true);
+      await invoke("import_world", {
+        serverId: selectedServer.name,
+        worldImport: buildWorldImportPayload(result.sourcePath, result.validation),
+        allowDestructiveOnLinked: selectedServer.linked ?? false
+      });
+      await loadBackups(selectedServer);
+      setUiToast({ tone: "success", message: "World imported." });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    } finally {
+      setWorldImportBusy(false);
+    }
+  };
+
+  const handleImportMods = async (kind: "folder" | "zip") => {
+    if (!selectedServer || !isTauri) return;
+    try {
+      const result = await pickAndValidateMods(kind);
+      if (!result) return;
+      const replaceExisting = await confirm(
+        `Replace the current mods of ${selectedServer.name}? Choose No to add ${result.validation.mod_count} mods alongside them.`,
+        { title: "Import mods", okLabel: "Replace", cancelLabel: "Add" }
+      );
+      setModsImportBusy(true);
+      const imported = await invoke<ModsImportResult>("import_mods", {
+        serverId: selectedServer.name,
+        modsImport: {
+          source_path: result.sourcePath,
+          source_kind: result.validation.source_kind,
+          staged_path: result.validation.staged_path ?? null
+        },
+        replaceExisting
+      });
+      await loadMods(selectedServer);
+      const notes = [
+        imported.skipped.length ? `${imported.skipped.length} already installed` : null,
+        imported.incompatible.length ? `${imported.incompatible.length} incompatible` : null
+      ].filter(Boolean);
+      setUiToast({
+        tone: imported.incompatible.length ? "error" : "success",
+        message: `${imported.copied.length} mods imported${notes.length ? ` (${notes.join(", ")})` : ""}.`
+      });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    } finally {
+      setModsImportBusy(false);
+    }
+  };
+
   const handleOpenBackupsFolder = async () => {
     if (!selectedServer) return;
     try {
@@ -2138,7 +2633,7 @@ function App() {
         await openPath(base);
       }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2146,19 +2641,67 @@ function App() {
     if (!selectedServer || !isTauri) return;
     let ok = false;
     try {
-      ok = await confirm("Restore this backup? Current world will be replaced.", {
-        title: "Restore backup"
-      });
+      ok = await confirm(
+        selectedServer.linked
+          ? `Restore this backup? The world in your linked folder ${selectedServer.server_dir} will be replaced.`
+          : "Restore this backup? Current world will be replaced.",
+        { title: "Restore backup" }
+      );
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       return;
     }
     if (!ok) return;
+    let password: string | null = null;
+    if (entry.encrypted) {
+      password = window.prompt("This backup is encrypted. Enter its password:");
+      if (!password) return;
+    }
     try {
-      await invoke("restore_backup", { serverId: selectedServer.name, backupId: entry.id });
+      await invoke("restore_backup", {
+        serverId: selectedServer.name,
+        backupId: entry.id,
+        allowDestructiveOnLinked: selectedServer.linked ?? false,
+        password
+      });
       setUiToast({ tone: "success", message: "Backup restored." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
+  const handleConsolidateBackups = async () => {
+    if (!selectedServer || !isTauri) return;
+    try {
+      await invoke<BackupEntry>("consolidate_backups", { serverId: selectedServer.name });
+      await loadBackups(selectedServer);
+      setUiToast({ tone: "success", message: "Incremental backups consolidated." });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
+  const handleReconcileBackups = async () => {
+    if (!selectedServer || !isTauri) return;
+    try {
+      const result = await invoke<BackupReconcileResult>("reconcile_backups", { serverId: selectedServer.name });
+      await loadBackups(selectedServer);
+      const changes = result.removed.length + result.adopted.length;
+      setUiToast({
+        tone: result.unreadable.length > 0 ? "error" : "success",
+        message:
+          changes === 0 && result.unreadable.length === 0
+            ? "Backup list matches the backups folder."
+            : [
+                result.removed.length > 0 ? `${result.removed.length} missing removed` : null,
+                result.adopted.length > 0 ? `${result.adopted.length} found and added` : null,
+                result.unreadable.length > 0 ? `unreadable: ${result.unreadable.join(", ")}` : null
+              ]
+                .filter(Boolean)
+                .join(" · ")
+      });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2170,7 +2713,7 @@ function App() {
         title: "Delete backup"
       });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       return;
     }
     if (!ok) return;
@@ -2179,7 +2722,7 @@ function App() {
       await loadBackups(selectedServer);
       setUiToast({ tone: "success", message: "Backup deleted." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2188,7 +2731,11 @@ function App() {
     if (!activeTarget || !isTauri) return;
     setDeleteBusy(true);
     try {
-      await invoke("delete_server", { serverId: activeTarget.name });
+      const result = await invoke<DeleteServerResult>("delete_server", {
+        serverId: activeTarget.name,
+        deleteBackups,
+        deleteMeta
+      });
       setServers((prev) => prev.filter((server) => server.name !== activeTarget.name));
       setServerIcons((prev) => {
         const next = { ...prev };
@@ -2202,9 +2749,12 @@ function App() {
         setActiveServerId(null);
       }
       changeView("servers");
-      setUiToast({ tone: "success", message: "Server deleted." });
+      setUiToast({
+        tone: "success",
+        message: `Server deleted. Freed ${(result.freed_bytes / 1024 / 1024).toFixed(1)} MB.`
+      });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setDeleteBusy(false);
       setDeleteTarget(null);
@@ -2219,7 +2769,7 @@ function App() {
       const list = await invoke<ModEntry[]>("list_mods", { serverId: server.name });
       setMods(list);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       setMods([]);
     } finally {
       setModsLoading(false);
@@ -2232,7 +2782,7 @@ function App() {
       const pack = await invoke<ModpackManifest>("get_modpack", { serverId: server.name });
       setModpack(pack);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       setModpack(null);
     }
   };
@@ -2244,7 +2794,7 @@ function App() {
       const sync = await invoke<ModSyncStatus>("check_mod_sync", { serverId: server.name });
       setModSync(sync);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       setModSync(null);
     } finally {
       setModSyncLoading(false);
@@ -2270,8 +2820,11 @@ function App() {
     try {
       const list = await invoke<BackupEntry[]>("list_backups", { serverId: server.name });
       setBackups(list.sort((a, b) => b.created_at.localeCompare(a.created_at)));
+      const found = await invoke<WorldInfo[]>("list_worlds", { serverId: server.name }).catch(() => []);
+      setWorlds(found);
+      setBackupExtraWorlds((current) => current.filter((name) => found.some((world) => world.name === name)));
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
       setBackups([]);
     } finally {
       setBackupsLoading(false);
@@ -2291,8 +2844,10 @@ function App() {
         discord_template_ram: meta.discord_template_ram || DEFAULT_DISCORD_TEMPLATES.ram
       };
       setServerMeta(normalized);
+      setStopGraceDraft(normalized.stop_grace_seconds ?? 10);
     } catch {
       setServerMeta(DEFAULT_SERVER_META);
+      setStopGraceDraft(10);
     }
   };
 
@@ -2323,11 +2878,22 @@ function App() {
           discordTemplateStart: next.discord_template_start,
           discordTemplateStop: next.discord_template_stop,
           discordTemplateCrash: next.discord_template_crash,
-          discordTemplateRam: next.discord_template_ram
+          discordTemplateRam: next.discord_template_ram,
+          tunnelAddress: next.tunnel_address ?? null,
+          backupCompression: next.backup_compression ?? "default",
+          incrementalBackups: next.incremental_backups ?? false,
+          fullBackupEvery: next.full_backup_every ?? 12,
+          processPriority: next.process_priority ?? "normal",
+          propertiesAuthoritative: next.properties_authoritative ?? false,
+          watchdogEnabled: next.watchdog_enabled ?? false,
+          watchdogTimeoutSeconds: next.watchdog_timeout_seconds ?? 120,
+          watchdogAction: next.watchdog_action ?? "notify",
+          stopGraceSeconds: next.stop_grace_seconds ?? 10,
+          autoExport: next.auto_export ?? DEFAULT_AUTO_EXPORT
         }
       });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2344,7 +2910,7 @@ function App() {
       setImportName(analysis.suggested_name);
       setImportMode("copy");
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2369,7 +2935,7 @@ function App() {
       await loadServerMeta(created);
       setUiToast({ tone: "success", message: "Server imported." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setImportBusy(false);
     }
@@ -2411,7 +2977,7 @@ function App() {
       });
       await loadMods(selectedServer);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2427,7 +2993,7 @@ function App() {
       await loadMods(selectedServer);
       setUiToast({ tone: "success", message: `Deleted ${deleted} mods.` });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setModsBulkBusy(false);
     }
@@ -2438,7 +3004,7 @@ function App() {
     setModMetaBusy(true);
     setModMetaError(null);
     try {
-      const manifest = await invoke<ModpackManifest>("add_mod_with_meta", {
+      const { manifest, warnings } = await invoke<AddModResult>("add_mod_with_meta", {
         serverId: selectedServer.name,
         sourcePath: modMetaPath,
         modId: modMetaId,
@@ -2450,9 +3016,13 @@ function App() {
       setModMetaPath(null);
       await loadMods(selectedServer);
       await refreshModSync(selectedServer);
-      setUiToast({ tone: "success", message: "Mod added." });
+      setUiToast(
+        warnings.length
+          ? { tone: "error", message: `Mod added, but: ${warnings.join(" ")}` }
+          : { tone: "success", message: "Mod added." }
+      );
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setModMetaError(message);
       setUiToast({ tone: "error", message });
     } finally {
@@ -2505,7 +3075,7 @@ function App() {
     try {
       await openPath(targetPath);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2515,7 +3085,7 @@ function App() {
       const settings = await invoke<AppSettings>("get_app_settings");
       setAppSettings(settings);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2527,7 +3097,7 @@ function App() {
       setAppSettings(saved);
       setUiToast({ tone: "success", message: "Settings saved." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setAppSettingsSaving(false);
     }
@@ -2538,7 +3108,7 @@ function App() {
     setUpdateChecking(true);
     setUpdateError(null);
     try {
-      const info = await invoke<UpdateInfo>("check_for_updates", { repo: UPDATE_REPO });
+      const info = await invoke<UpdateInfo>("check_for_updates", { repo: UPDATE_REPO, force: !silent });
       setUpdateInfo(info);
       if (info.update_available) {
         setUpdateModalOpen(true);
@@ -2546,7 +3116,7 @@ function App() {
         setUiToast({ tone: "success", message: "You are up to date." });
       }
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setUpdateError(message);
       if (!silent) {
         setUiToast({ tone: "error", message });
@@ -2562,14 +3132,17 @@ function App() {
     setUpdateError(null);
     try {
       window.localStorage.setItem(UPDATE_SKIP_CRASH_KEY, "true");
-      await invoke("install_update", { downloadUrl: updateInfo.download_url });
+      setUpdateDownloadPercent(0);
+      const installerPath = await invoke<string>("download_update", { downloadUrl: updateInfo.download_url });
+      await invoke("install_update", { installerPath });
       setUpdateModalOpen(false);
     } catch (err) {
-      const message = String(err);
+      const message = errorMessage(err);
       setUpdateError(message);
       setUiToast({ tone: "error", message });
     } finally {
       setUpdateDownloading(false);
+      setUpdateDownloadPercent(null);
     }
   };
 
@@ -2582,7 +3155,7 @@ function App() {
         window.localStorage.removeItem(UPDATE_SKIP_CRASH_KEY);
       }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2603,7 +3176,7 @@ function App() {
       await openPath(path);
       setUiToast({ tone: "success", message: "Crash reports exported." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2615,7 +3188,7 @@ function App() {
       const report = await invoke<CrashReport>("get_crash_report", { fileName });
       setActiveCrashReport(report);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setCrashLoading(false);
     }
@@ -2630,7 +3203,7 @@ function App() {
         setActiveCrashReport(null);
       }
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     }
   };
 
@@ -2641,7 +3214,95 @@ function App() {
       setCrashReports([]);
       setActiveCrashReport(null);
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
+  const loadTempUsage = async () => {
+    if (!isTauri) return;
+    try {
+      setTempUsage(await invoke<TempUsage>("get_temp_usage"));
+    } catch {
+      setTempUsage(null);
+    }
+  };
+
+  const handleExportAppConfig = async () => {
+    if (!isTauri) return;
+    const destination = await save({
+      filters: [{ name: "Zip", extensions: ["zip"] }],
+      defaultPath: "gamehost-settings.zip"
+    });
+    if (!destination) return;
+    try {
+      const warnings = await invoke<string[]>("export_app_config", { destination });
+      setUiToast({ tone: "success", message: ["Settings exported.", ...warnings].join(" ") });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
+  const handleRelocateServer = async (serverName: string) => {
+    const selection = await open({ directory: true, multiple: false, title: `Where is the ${serverName} folder?` });
+    if (!selection || Array.isArray(selection)) return false;
+    const relocated = await invoke<ServerConfig>("relocate_server", { serverId: serverName, newPath: selection });
+    const updated = { ...relocated, healthy: true };
+    setServers((prev) => prev.map((server) => (server.name === serverName ? updated : server)));
+    setSelectedServer((prev) => (prev?.name === serverName ? updated : prev));
+    return true;
+  };
+
+  const handleImportAppConfig = async (merge: boolean) => {
+    if (!isTauri) return;
+    const selection = await open({ multiple: false, filters: [{ name: "Zip", extensions: ["zip"] }] });
+    if (!selection || Array.isArray(selection)) return;
+    if (
+      !merge &&
+      !(await confirm("Replace all servers and app settings with the imported ones? Server folders are not deleted.", {
+        title: "Import settings",
+        kind: "warning"
+      }))
+    ) {
+      return;
+    }
+    try {
+      const result = await invoke<AppConfigImport>("import_app_config", { zipPath: selection, merge, replace: !merge });
+      const list = await invoke<ServerConfig[]>("list_servers");
+      setServers(list);
+      await loadAppSettings();
+      for (const name of result.needs_relocation) {
+        const locate = await confirm(`The folder of ${name} was not found on this PC. Locate it now?`, {
+          title: "Server folder missing"
+        });
+        if (!locate) continue;
+        try {
+          await handleRelocateServer(name);
+        } catch (err) {
+          setUiToast({ tone: "error", message: errorMessage(err) });
+        }
+      }
+      const skipped = result.skipped.length ? ` Skipped existing: ${result.skipped.join(", ")}.` : "";
+      setUiToast({
+        tone: result.warnings.length ? "error" : "success",
+        message: [`Imported ${result.imported.length} servers.${skipped}`, ...result.warnings].join(" ")
+      });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    }
+  };
+
+  const clearTempFiles = async () => {
+    if (!isTauri) return;
+    try {
+      const freed = await invoke<TempUsage>("clear_temp");
+      setUiToast({
+        tone: "success",
+        message: `Freed ${(freed.bytes / 1024 / 1024).toFixed(1)} MB of temporary files.`
+      });
+    } catch (err) {
+      setUiToast({ tone: "error", message: errorMessage(err) });
+    } finally {
+      await loadTempUsage();
     }
   };
 
@@ -2705,7 +3366,7 @@ function App() {
       await writeTextFile(propertiesPath, `${updatedLines.join("\n")}\n`);
       setUiToast({ tone: "success", message: "MOTD saved." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setMotdSaving(false);
     }
@@ -2752,7 +3413,7 @@ function App() {
       await loadServerIcons([server]);
       setUiToast({ tone: "success", message: "Server icon updated." });
     } catch (err) {
-      setUiToast({ tone: "error", message: String(err) });
+      setUiToast({ tone: "error", message: errorMessage(err) });
     } finally {
       setIconSaving(false);
     }
@@ -2798,6 +3459,12 @@ function App() {
       >
         <TitleBar uiToast={uiToast} onMinimize={handleMinimize} onMaximize={handleMaximize} onClose={handleClose} />
 
+        {offline && (
+          <div className="mx-6 mt-2 rounded-2xl border border-danger/30 bg-danger/10 px-4 py-2 text-xs text-danger">
+            You're offline. Downloads and version lists are unavailable; cached data is shown where possible.
+          </div>
+        )}
+
         {serverReady && effectiveAppSettings.smart_join_panel_enabled && !smartJoinDismissed && (
           <motion.div
             variants={item}
@@ -2869,6 +3536,11 @@ function App() {
             confirmText={deleteConfirm}
             deleteMatches={deleteMatches}
             deleteBusy={deleteBusy}
+            preview={deletePreview}
+            deleteBackups={deleteBackups}
+            deleteMeta={deleteMeta}
+            onDeleteBackupsChange={setDeleteBackups}
+            onDeleteMetaChange={setDeleteMeta}
             onConfirmTextChange={setDeleteConfirm}
             onCancel={() => setDeleteTarget(null)}
             onDelete={() => handleDeleteServer()}
@@ -2978,6 +3650,7 @@ function App() {
             open={launcherChoiceOpen}
             onClose={() => setLauncherChoiceOpen(false)}
             onChoose={handleChooseLauncher}
+            launchers={installedLaunchers}
             launcherPath={effectiveAppSettings.launcher_path ?? null}
             onPickLauncherPath={handlePickLauncherPath}
             onClearLauncherPath={handleClearLauncherPath}
@@ -3207,7 +3880,7 @@ function App() {
                           await performLaunchForServer(selectedServer);
                         }
                       } catch (err) {
-                        setModSyncChoiceError(String(err));
+                        setModSyncChoiceError(errorMessage(err));
                         setModSyncChoiceBusy(false);
                       }
                     }}
@@ -3296,7 +3969,7 @@ function App() {
                 <div className="mt-5 flex items-center justify-end gap-3">
                   <SubtleButton onClick={() => setUpdateModalOpen(false)}>Later</SubtleButton>
                   <PrimaryButton onClick={handleUpdateNow} disabled={updateDownloading || !updateInfo?.download_url}>
-                    {updateDownloading ? "Downloading..." : "Update now"}
+                    {updateDownloading ? `Downloading${updateDownloadPercent !== null ? ` ${updateDownloadPercent}%` : "..."}` : "Update now"}
                   </PrimaryButton>
                 </div>
                 <p className="mt-3 text-xs text-muted">We will download and open the installer.</p>
@@ -3515,7 +4188,7 @@ function App() {
                           onClick={handleUpdateNow}
                           disabled={!updateInfo?.update_available || updateDownloading || !updateInfo?.download_url}
                         >
-                          {updateDownloading ? "Downloading..." : "Update now"}
+                          {updateDownloading ? `Downloading${updateDownloadPercent !== null ? ` ${updateDownloadPercent}%` : "..."}` : "Update now"}
                         </SubtleButton>
                       </div>
                       <p className="text-xs text-muted">Updates are installed manually and never run silently.</p>
@@ -3651,6 +4324,105 @@ function App() {
                           ))}
                         </div>
                       </SettingRow>
+                      <SettingRow
+                        label="Download proxy"
+                        description="Route all downloads through this proxy. Leave empty to use the system settings."
+                      >
+                        <input
+                          key={effectiveAppSettings.proxy_url ?? ""}
+                          className="w-56 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                          placeholder="http://proxy:3128"
+                          defaultValue={effectiveAppSettings.proxy_url ?? ""}
+                          disabled={appSettingsSaving}
+                          onBlur={(event) => {
+                            const value = event.target.value.trim();
+                            if (value === (effectiveAppSettings.proxy_url ?? "")) return;
+                            saveAppSettings({ ...effectiveAppSettings, proxy_url: value || null });
+                          }}
+                        />
+                      </SettingRow>
+                      <SettingRow
+                        label="Status endpoint"
+                        description={`Serve read-only /status and /metrics on port ${
+                          effectiveAppSettings.status_server_port ?? 25580
+                        } for monitoring from another device.`}
+                      >
+                        <Switch.Root
+                          checked={Boolean(effectiveAppSettings.status_server_enabled)}
+                          onCheckedChange={(value) =>
+                            saveAppSettings({ ...effectiveAppSettings, status_server_enabled: value })
+                          }
+                          className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                          disabled={appSettingsSaving}
+                        >
+                          <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                        </Switch.Root>
+                      </SettingRow>
+                      {effectiveAppSettings.status_server_enabled && (
+                        <>
+                          <SettingRow label="Status port" description="Port the status endpoint listens on.">
+                            <input
+                              key={effectiveAppSettings.status_server_port ?? 25580}
+                              type="number"
+                              min={1024}
+                              max={65535}
+                              className="w-28 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                              defaultValue={effectiveAppSettings.status_server_port ?? 25580}
+                              disabled={appSettingsSaving}
+                              onBlur={(event) => {
+                                const value = Number(event.target.value);
+                                if (!Number.isInteger(value) || value === effectiveAppSettings.status_server_port) return;
+                                saveAppSettings({ ...effectiveAppSettings, status_server_port: value });
+                              }}
+                            />
+                          </SettingRow>
+                          <SettingRow
+                            label="Reachable on LAN"
+                            description="Listen on all network interfaces instead of this computer only. Requires a status token."
+                          >
+                            <Switch.Root
+                              checked={Boolean(effectiveAppSettings.status_server_lan)}
+                              onCheckedChange={(value) =>
+                                saveAppSettings({ ...effectiveAppSettings, status_server_lan: value })
+                              }
+                              className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                              disabled={appSettingsSaving}
+                            >
+                              <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                            </Switch.Root>
+                          </SettingRow>
+                          <SettingRow
+                            label="Status token"
+                            description="Require Authorization: Bearer <token>. Can only be left empty while the status server is limited to this computer."
+                          >
+                            <input
+                              key={effectiveAppSettings.status_server_token ?? ""}
+                              type="password"
+                              className="w-56 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                              defaultValue={effectiveAppSettings.status_server_token ?? ""}
+                              disabled={appSettingsSaving}
+                              onBlur={(event) => {
+                                const value = event.target.value.trim();
+                                if (value === (effectiveAppSettings.status_server_token ?? "")) return;
+                                saveAppSettings({ ...effectiveAppSettings, status_server_token: value || null });
+                              }}
+                            />
+                          </SettingRow>
+                        </>
+                      )}
+                      <SettingRow
+                        label="Webhooks"
+                        description="Post server, backup and player events to Discord or any HTTPS endpoint."
+                      >
+                        <div className="w-96 max-w-full">
+                          <WebhookSettings
+                            webhooks={effectiveAppSettings.webhooks ?? []}
+                            disabled={appSettingsSaving}
+                            onChange={(webhooks) => saveAppSettings({ ...effectiveAppSettings, webhooks })}
+                            onNotify={(tone, message) => setUiToast({ tone, message })}
+                          />
+                        </div>
+                      </SettingRow>
                     </div>
                   </Card>
 
@@ -3666,6 +4438,30 @@ function App() {
                           <SubtleButton onClick={openAppData}>Open folder</SubtleButton>
                         </div>
                       </div>
+                      <div className="rounded-2xl border border-white/10 bg-white/5 px-4 py-2">
+                        <p className="text-xs uppercase tracking-[0.2em] text-muted">Move to another PC</p>
+                        <p className="mt-1 text-xs text-muted">
+                          Settings, servers and their preferences. Worlds and server files are not included.
+                        </p>
+                        <div className="mt-2 flex flex-wrap items-center gap-2">
+                          <SubtleButton onClick={handleExportAppConfig}>Export settings</SubtleButton>
+                          <SubtleButton onClick={() => handleImportAppConfig(true)}>Import and merge</SubtleButton>
+                          <SubtleButton onClick={() => handleImportAppConfig(false)}>Import and replace</SubtleButton>
+                        </div>
+                      </div>
+                      <div className="rounded-2xl border border-white/10 bg-white/5 px-4 py-2">
+                        <p className="text-xs uppercase tracking-[0.2em] text-muted">Temporary files</p>
+                        <p className="mt-1 text-xs text-muted">
+                          {tempUsage
+                            ? `${(tempUsage.bytes / 1024 / 1024).toFixed(1)} MB in ${tempUsage.entries} staged import(s). Cleaned automatically after 24 hours.`
+                            : "Staged imports are cleaned automatically after 24 hours."}
+                        </p>
+                        <div className="mt-2 flex items-center gap-2">
+                          <SubtleButton onClick={clearTempFiles} disabled={!tempUsage || tempUsage.entries === 0}>
+                            Clear now
+                          </SubtleButton>
+                        </div>
+                      </div>
                       <div className="rounded-2xl border border-white/10 bg-white/5 px-4 py-2">
                         <p className="text-xs uppercase tracking-[0.2em] text-muted">Privacy</p>
                         <p className="mt-1 text-xs text-muted">Read how we handle data and crashes.</p>
@@ -3856,13 +4652,30 @@ function App() {
                               Version {server.version} · RAM {server.ram_gb} GB
                               {server.linked ? " · Linked" : ""}
                             </p>
+                            {server.healthy === false && (
+                              <p className="mt-1 text-xs text-danger">
+                                Folder missing ·{" "}
+                                <button
+                                  type="button"
+                                  className="underline"
+                                  onClick={(event) => {
+                                    event.stopPropagation();
+                                    handleRelocateServer(server.name).catch((err) =>
+                                      setUiToast({ tone: "error", message: errorMessage(err) })
+                                    );
+                                  }}
+                                >
+                                  Locate
+                                </button>
+                              </p>
+                            )}
                             <div className="mt-4 flex flex-wrap gap-2">
                               <SubtleButton
                                 onClick={(event) => {
                                   event.stopPropagation();
                                   handleServerAction("start", server);
                                 }}
-                                disabled={!cardActions.canStart}
+                                disabled={!cardActions.canStart || server.healthy === false}
                                 className="bg-one/20 text-one hover:bg-one/30"
                               >
                                 Start
@@ -4303,6 +5116,14 @@ function App() {
                       {" · RAM "}{selectedServer.ram_gb} GB
                       {serverMetadata?.modCount ? ` · ${serverMetadata.modCount} Mods Detected` : ""}
                     </p>
+                    {serverMeta?.stats && (
+                      <p className="text-xs text-muted">
+                        {formatRunStats(
+                          serverMeta.stats,
+                          activeServerId === selectedServer.name ? uptimeSeconds : null
+                        )}
+                      </p>
+                    )}
                   </div>
                 </div>
                 <div className="flex items-center gap-3">
@@ -4314,6 +5135,14 @@ function App() {
                   ) : (
                     <StatusPill status={serverStatusFor(selectedServer)} />
                   )}
+                  {(pendingChangesByName[selectedServer.name]?.length ?? 0) > 0 && (
+                    <span
+                      className="rounded-full bg-amber-500/20 px-3 py-1 text-xs font-semibold text-amber-200"
+                      title={pendingChangesByName[selectedServer.name].join(", ")}
+                    >
+                      Restart to apply
+                    </span>
+                  )}
                   <div className="rounded-full border border-white/10 bg-white/5 p-1 transition hover:border-primary/40 hover:bg-white/10">
                     <PrimaryButton
                       onClick={() => handleServerAction("start")}
@@ -4330,6 +5159,13 @@ function App() {
                   >
                     Stop
                   </SubtleButton>
+                  <SubtleButton
+                    onClick={handleKillServer}
+                    disabled={!actionState.canStop}
+                    className="text-danger hover:bg-danger/20"
+                  >
+                    Force kill
+                  </SubtleButton>
                   <SubtleButton
                     onClick={() => handleServerAction("restart")}
                     disabled={!actionState.canRestart}
@@ -4699,11 +5535,32 @@ function App() {
                         {supportsMods && modMismatch && (
                           <div className="flex flex-wrap items-center gap-2">
                             <PrimaryButton onClick={handleDownloadMissingMods} disabled={modSyncLoading}>
-                              {modSyncLoading ? "Syncing..." : "Download missing mods"}
+                              {modDownload
+                                ? `Downloading... ${Math.round(modDownload.percent)}%`
+                                : modSyncLoading
+                                  ? "Syncing..."
+                                  : "Download missing mods"}
                             </PrimaryButton>
+                            {modDownload && (
+                              <SubtleButton
+                                onClick={() => invoke("cancel_operation", { operationId: modDownload.operationId })}
+                              >
+                                Cancel
+                              </SubtleButton>
+                            )}
                             <SubtleButton onClick={handleSyncModsCheck}>Sync mods with server</SubtleButton>
                           </div>
                         )}
+                        {supportsMods && (
+                          <div className="flex flex-wrap items-center gap-2">
+                            <SubtleButton onClick={() => handleImportMods("zip")} disabled={modsImportBusy}>
+                              {modsImportBusy ? "Importing..." : "Import modpack"}
+                            </SubtleButton>
+                            <SubtleButton onClick={() => handleImportMods("folder")} disabled={modsImportBusy}>
+                              Import mods folder
+                            </SubtleButton>
+                          </div>
+                        )}
                         {supportsMods && (
                           <div className="rounded-2xl border border-white/10 bg-white/5 px-4 py-3">
                             <div className="flex items-center justify-between gap-3">
@@ -4776,11 +5633,86 @@ function App() {
                       >
                     <Card title="Live Console">
                       <ConsoleView
+                        serverName={selectedServer?.name ?? null}
+                        running={!!selectedServer && selectedServer.name === activeServerId && status === "RUNNING"}
                         entries={consoleEntries}
                         players={onlinePlayers}
                         onSendCommand={sendCommand}
+                        loadHistory={async () => {
+                          if (!selectedServer || !isTauri) return [];
+                          const history = await invoke<CommandHistoryEntry[]>("get_command_history", {
+                            serverId: selectedServer.name,
+                            limit: 100
+                          });
+                          return history.map((entry) => entry.command);
+                        }}
+                        suggestCommands={async (prefix) => {
+                          if (!selectedServer || !isTauri) return [];
+                          return invoke<string[]>("suggest_commands", { serverId: selectedServer.name, prefix });
+                        }}
+                        loadCommands={async () => {
+                          if (!selectedServer || !isTauri) return [];
+                          return invoke<AvailableCommand[]>("get_available_commands", { serverId: selectedServer.name });
+                        }}
+                        onClearHistory={async () => {
+                          if (!selectedServer || !isTauri) return;
+                          try {
+                            await invoke("clear_command_history", { serverId: selectedServer.name });
+                          } catch (err) {
+                            setUiToast({ tone: "error", message: errorMessage(err) });
+                          }
+                        }}
                       />
                     </Card>
+                    {selectedServer && (
+                      <Card title="Recent Issues">
+                        <RecentIssuesPanel
+                          serverName={selectedServer.name}
+                          loadIssues={() => invoke<ConsoleIssue[]>("get_recent_issues", { serverId: selectedServer.name })}
+                        />
+                      </Card>
+                    )}
+                    {selectedServer && (
+                      <Card title="Log Files">
+                        <ServerLogsPanel
+                          serverName={selectedServer.name}
+                          loadFiles={() =>
+                            invoke<ServerLogFile[]>("list_server_logs", { serverId: selectedServer.name })
+                          }
+                          readLog={(file) =>
+                            invoke<string[]>("read_server_log", {
+                              serverId: selectedServer.name,
+                              file,
+                              tailLines: 500
+                            })
+                          }
+                          searchLogs={(pattern) =>
+                            invoke<ServerLogMatch[]>("search_server_logs", {
+                              serverId: selectedServer.name,
+                              pattern,
+                              maxMatches: 200
+                            })
+                          }
+                        />
+                      </Card>
+                    )}
+                    {selectedServer && (
+                      <Card title="Whitelist & Operators">
+                        <PlayerListsPanel
+                          importPlayers={(list, names) =>
+                            invoke<PlayerImportResult>(list === "ops" ? "import_ops" : "import_whitelist", {
+                              serverId: selectedServer.name,
+                              names
+                            })
+                          }
+                          exportPlayers={(list) =>
+                            invoke<string[]>(list === "ops" ? "export_ops" : "export_whitelist", {
+                              serverId: selectedServer.name
+                            })
+                          }
+                        />
+                      </Card>
+                    )}
                       </motion.div>
                     )}
                     {detailTab === "settings" && (
@@ -4894,12 +5826,251 @@ function App() {
                             </Select.Root>
                           </SettingRow>
                         )}
+                        {serverMeta?.auto_backup && (
+                          <SettingRow
+                            label="Incremental backups"
+                            description={`Scheduled backups only store changed files; a full backup runs every ${serverMeta.full_backup_every ?? 12} increments.`}
+                          >
+                            <Switch.Root
+                              checked={serverMeta.incremental_backups ?? false}
+                              onCheckedChange={(value) => saveServerMeta({ incremental_backups: value })}
+                              className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                            >
+                              <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                            </Switch.Root>
+                          </SettingRow>
+                        )}
+                        <SettingRow
+                          label="Scheduled world export"
+                          description={
+                            serverMeta?.auto_export?.destination_dir
+                              ? `Copies the world as a zip into ${serverMeta.auto_export.destination_dir}.`
+                              : "Copy the world as a zip into a folder of your choice, e.g. OneDrive or Dropbox."
+                          }
+                        >
+                          <SubtleButton
+                            onClick={async () => {
+                              const selection = await open({ directory: true, multiple: false, title: "Export folder" });
+                              if (!selection || Array.isArray(selection)) return;
+                              saveServerMeta({
+                                auto_export: { ...(serverMeta?.auto_export ?? DEFAULT_AUTO_EXPORT), destination_dir: selection }
+                              });
+                            }}
+                          >
+                            Choose folder
+                          </SubtleButton>
+                          <Switch.Root
+                            checked={serverMeta?.auto_export?.enabled ?? false}
+                            disabled={!serverMeta?.auto_export?.destination_dir}
+                            onCheckedChange={(value) =>
+                              saveServerMeta({
+                                auto_export: { ...(serverMeta?.auto_export ?? DEFAULT_AUTO_EXPORT), enabled: value }
+                              })
+                            }
+                            className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                          >
+                            <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                          </Switch.Root>
+                        </SettingRow>
+                        {serverMeta?.auto_export?.enabled && (
+                          <SettingRow
+                            label="Export schedule"
+                            description={`Keeps the newest ${serverMeta.auto_export.keep_last_n} exports. Skipped when the world has not changed.${
+                              serverMeta.auto_export.last_export_at
+                                ? ` Last export ${new Date(serverMeta.auto_export.last_export_at).toLocaleString()}.`
+                                : ""
+                            }`}
+                          >
+                            <div className="flex flex-wrap items-center gap-2">
+                              {AUTO_EXPORT_INTERVALS.map((hours) => (
+                                <SubtleButton
+                                  key={hours}
+                                  className={classNames(
+                                    serverMeta.auto_export?.interval_hours === hours
+                                      ? "bg-one/20 text-one ring-1 ring-one/40"
+                                      : ""
+                                  )}
+                                  onClick={() =>
+                                    saveServerMeta({
+                                      auto_export: { ...(serverMeta.auto_export ?? DEFAULT_AUTO_EXPORT), interval_hours: hours }
+                                    })
+                                  }
+                                >
+                                  {hours === 168 ? "Weekly" : hours === 24 ? "Daily" : `${hours}h`}
+                                </SubtleButton>
+                              ))}
+                              <input
+                                key={serverMeta.auto_export.keep_last_n}
+                                type="number"
+                                min={1}
+                                max={365}
+                                title="Exports to keep"
+                                className="w-20 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                                defaultValue={serverMeta.auto_export.keep_last_n}
+                                onBlur={(event) => {
+                                  const value = Number(event.target.value);
+                                  if (!Number.isInteger(value) || value === serverMeta.auto_export?.keep_last_n) return;
+                                  saveServerMeta({
+                                    auto_export: { ...(serverMeta.auto_export ?? DEFAULT_AUTO_EXPORT), keep_last_n: value }
+                                  });
+                                }}
+                              />
+                            </div>
+                          </SettingRow>
+                        )}
+                        <SettingRow
+                          label="Process priority"
+                          description="CPU priority of the server process. Below normal keeps games and other apps responsive."
+                        >
+                          <Select.Root
+                            value={serverMeta?.process_priority ?? "normal"}
+                            onValueChange={(value) => saveServerMeta({ process_priority: value as ProcessPriority })}
+                          >
+                            <Select.Trigger className="flex w-40 items-center justify-between rounded-full border border-white/10 bg-white/10 px-4 py-2 text-xs font-semibold text-text transition focus:border-one/60 focus:outline-none">
+                              <Select.Value />
+                              <Select.Icon className="text-muted">▾</Select.Icon>
+                            </Select.Trigger>
+                            <Select.Portal>
+                              <Select.Content
+                                position="popper"
+                                side="bottom"
+                                align="start"
+                                sideOffset={8}
+                                avoidCollisions={false}
+                                className="select-content z-50 overflow-hidden rounded-2xl border border-white/10 shadow-soft"
+                              >
+                                <Select.Viewport className="bg-surface p-1">
+                                  {PROCESS_PRIORITIES.map((option) => (
+                                    <Select.Item
+                                      key={option.value}
+                                      value={option.value}
+                                      className="cursor-pointer rounded-xl px-3 py-2 text-sm text-text outline-none data-highlighted:bg-white/15 data-highlighted:text-white"
+                                    >
+                                      <Select.ItemText>{option.label}</Select.ItemText>
+                                    </Select.Item>
+                                  ))}
+                                </Select.Viewport>
+                              </Select.Content>
+                            </Select.Portal>
+                          </Select.Root>
+                        </SettingRow>
+                        <SettingRow
+                          label="Keep server.properties edits"
+                          description="On start, take hand edits to server.properties into the settings here instead of overwriting them."
+                        >
+                          <Switch.Root
+                            checked={serverMeta?.properties_authoritative ?? false}
+                            onCheckedChange={(value) => saveServerMeta({ properties_authoritative: value })}
+                            className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                          >
+                            <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                          </Switch.Root>
+                        </SettingRow>
+                        <SettingRow
+                          label="Hang watchdog"
+                          description="Act when a running server stops answering console commands."
+                        >
+                          <Switch.Root
+                            checked={serverMeta?.watchdog_enabled ?? false}
+                            onCheckedChange={(value) => saveServerMeta({ watchdog_enabled: value })}
+                            className="relative h-6 w-11 rounded-full bg-white/15 transition data-[state=checked]:bg-secondary"
+                          >
+                            <Switch.Thumb className="block h-5 w-5 translate-x-0.5 rounded-full bg-white transition data-[state=checked]:translate-x-5" />
+                          </Switch.Root>
+                        </SettingRow>
+                        {serverMeta?.watchdog_enabled && (
+                          <SettingRow
+                            label="When unresponsive"
+                            description="Seconds without a response, and what to do then. Applies from the next start."
+                          >
+                            <div className="flex flex-wrap items-center gap-2">
+                              <input
+                                key={serverMeta.watchdog_timeout_seconds ?? 120}
+                                type="number"
+                                min={30}
+                                max={3600}
+                                className="w-20 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                                defaultValue={serverMeta.watchdog_timeout_seconds ?? 120}
+                                onBlur={(event) => {
+                                  const value = Math.min(3600, Math.max(30, Number(event.target.value) || 120));
+                                  if (value !== serverMeta.watchdog_timeout_seconds) {
+                                    saveServerMeta({ watchdog_timeout_seconds: value });
+                                  }
+                                }}
+                              />
+                              {WATCHDOG_ACTIONS.map((option) => (
+                                <SubtleButton
+                                  key={option.value}
+                                  className={classNames(
+                                    (serverMeta.watchdog_action ?? "notify") === option.value
+                                      ? "bg-one/20 text-one ring-1 ring-one/40"
+                                      : ""
+                                  )}
+                                  onClick={() => saveServerMeta({ watchdog_action: option.value })}
+                                >
+                                  {option.label}
+                                </SubtleButton>
+                              ))}
+                            </div>
+                          </SettingRow>
+                        )}
                         <div className="flex items-center justify-between">
                           <p className="text-xs text-muted">Settings update instantly for this server profile.</p>
                           <SubtleButton onClick={() => changeDetailTab("advanced")}>Advanced settings</SubtleButton>
                         </div>
+                        <SettingRow
+                          label="Stopping"
+                          description="Console command that shuts the server down, and how many seconds it gets to save before it is killed. Proxies use end."
+                        >
+                          <div className="flex items-center gap-2">
+                            <input
+                              className="w-28 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                              value={stopCommandDraft}
+                              placeholder="stop"
+                              onChange={(event) => setStopCommandDraft(event.target.value)}
+                            />
+                            <input
+                              type="number"
+                              min={5}
+                              max={600}
+                              className="w-20 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                              value={stopGraceDraft}
+                              onChange={(event) =>
+                                setStopGraceDraft(Math.min(600, Math.max(5, Number(event.target.value) || 10)))
+                              }
+                            />
+                          </div>
+                        </SettingRow>
+                        <SettingRow
+                          label="Port"
+                          description="Game port players connect to. It must not be shared with another server's game, RCON or Bedrock port."
+                        >
+                          <div className="flex items-center gap-2">
+                            <input
+                              type="number"
+                              min={1}
+                              max={65535}
+                              className="w-24 rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+                              value={portDraft}
+                              onChange={(event) =>
+                                setPortDraft(Math.min(65535, Math.max(1, Number(event.target.value) || 25565)))
+                              }
+                            />
+                            <SubtleButton
+                              onClick={async () => {
+                                try {
+                                  setPortDraft(await invoke<number>("suggest_free_port", { startFrom: 25565 }));
+                                } catch (err) {
+                                  setUiToast({ tone: "error", message: errorMessage(err) });
+                                }
+                              }}
+                            >
+                              Find free port
+                            </SubtleButton>
+                          </div>
+                        </SettingRow>
                         <div className="flex items-center justify-between">
-                          <p className="text-xs text-muted">Apply online-mode changes for this server.</p>
+                          <p className="text-xs text-muted">Apply online-mode, port and stop changes for this server.</p>
                           <PrimaryButton onClick={handleSaveConfig} disabled={configSaving}>
                             {configSaving ? "Saving..." : "Apply access"}
                           </PrimaryButton>
@@ -5121,6 +6292,47 @@ function App() {
                             {reinstallBusy ? "Reinstalling..." : "Reinstall server"}
                           </PrimaryButton>
                         </div>
+                        <div className="flex items-center justify-between">
+                          <p className="text-xs text-muted">Check the server jar, or download it again without touching worlds.</p>
+                          <div className="flex items-center gap-2">
+                            <SubtleButton onClick={() => handleVerifyServerFiles(false)} disabled={serverFilesBusy}>
+                              Verify files
+                            </SubtleButton>
+                            <SubtleButton
+                              onClick={() => handleVerifyServerFiles(true)}
+                              disabled={serverFilesBusy || selectedServer.server_type === "fabric"}
+                            >
+                              {serverFilesBusy ? "Working..." : "Repair files"}
+                            </SubtleButton>
+                          </div>
+                        </div>
+                        {(selectedServer.needs_relocation || selectedServer.healthy === false) && (
+                          <div className="flex items-center justify-between">
+                            <p className="text-xs text-danger">
+                              This server's folder was not found at {selectedServer.server_dir}.
+                            </p>
+                            <SubtleButton
+                              onClick={() =>
+                                handleRelocateServer(selectedServer.name).catch((err) =>
+                                  setUiToast({ tone: "error", message: errorMessage(err) })
+                                )
+                              }
+                            >
+                              Locate folder
+                            </SubtleButton>
+                          </div>
+                        )}
+                        <div className="flex items-center justify-between">
+                          <p className="text-xs text-muted">Scan region files for damage after a crash or a forced stop.</p>
+                          <SubtleButton
+                            onClick={handleValidateWorld}
+                            disabled={worldValidateProgress !== null || activeServerId === selectedServer.name}
+                          >
+                            {worldValidateProgress !== null
+                              ? `Checking... ${Math.round(worldValidateProgress)}%`
+                              : "Validate world"}
+                          </SubtleButton>
+                        </div>
                       </div>
                     </Card>
                     <Card title="Network Helper">
@@ -5146,9 +6358,18 @@ function App() {
                                 {network?.port_open ? "Open" : "Closed"}
                               </p>
                             </div>
-                            <SubtleButton onClick={refreshNetwork}>Refresh</SubtleButton>
+                            <SubtleButton onClick={() => refreshNetwork(true)}>Refresh</SubtleButton>
                           </div>
                           <p className="text-xs text-muted">Port forwarding is needed for friends outside your network.</p>
+                          {network?.cgnat_suspected && network.message && (
+                            <p className="text-xs text-danger">{network.message}</p>
+                          )}
+                          {network?.lan_only && network.message && (
+                            <p className="text-xs text-muted">{network.message}</p>
+                          )}
+                          {network?.stale && (
+                            <p className="text-xs text-muted">Offline: showing the last known public address.</p>
+                          )}
                         </div>
                         {!network?.port_open && (
                           <div className="grid gap-3 rounded-2xl border border-white/10 bg-white/5 px-4 py-4">
@@ -5191,6 +6412,32 @@ function App() {
                             />
                             Include The End
                           </label>
+                          {worlds
+                            .filter((world) => !world.standard)
+                            .map((world) => (
+                              <label key={world.name} className="flex items-center gap-2 text-xs text-muted">
+                                <input
+                                  type="checkbox"
+                                  checked={backupExtraWorlds.includes(world.name)}
+                                  onChange={(event) =>
+                                    setBackupExtraWorlds((current) =>
+                                      event.target.checked
+                                        ? [...current, world.name]
+                                        : current.filter((name) => name !== world.name)
+                                    )
+                                  }
+                                />
+                                {world.name} · {(world.size_bytes / 1024 / 1024).toFixed(0)} MB
+                              </label>
+                            ))}
+                          <input
+                            type="password"
+                            className="rounded-xl border border-white/10 bg-white/5 px-3 py-1 text-xs text-text focus:border-one/60 focus:outline-none"
+                            placeholder="Password (optional)"
+                            autoComplete="new-password"
+                            value={backupPassword}
+                            onChange={(event) => setBackupPassword(event.target.value)}
+                          />
                         </div>
                         <div className="flex flex-wrap items-center gap-3">
                           <PrimaryButton onClick={handleCreateBackup}>
@@ -5199,6 +6446,9 @@ function App() {
                           <SubtleButton onClick={handleExportWorld}>
                             {exportProgress !== null ? "Exporting..." : "Export world"}
                           </SubtleButton>
+                          <SubtleButton onClick={() => handleImportWorld("zip")} disabled={worldImportBusy}>
+                            {worldImportBusy ? "Importing..." : "Import world"}
+                          </SubtleButton>
                           <SubtleButton onClick={handleOpenBackupsFolder}>
                             Open backups folder
                           </SubtleButton>
@@ -5219,6 +6469,14 @@ function App() {
                           <div className="flex items-center justify-between">
                             <p className="text-xs uppercase tracking-[0.2em] text-muted">Available backups</p>
                             {backupsLoading && <span className="text-xs text-muted">Loading...</span>}
+                            {!backupsLoading && (
+                              <div className="flex items-center gap-2">
+                                <SubtleButton onClick={handleReconcileBackups}>Check files</SubtleButton>
+                                {backups[backups.length - 1]?.kind === "incremental" && (
+                                  <SubtleButton onClick={handleConsolidateBackups}>Consolidate</SubtleButton>
+                                )}
+                              </div>
+                            )}
                           </div>
                           {backups.length === 0 ? (
                             <p className="mt-2 text-xs text-muted">No backups created yet.</p>
@@ -5230,11 +6488,22 @@ function App() {
                                   className="flex flex-wrap items-center justify-between gap-3 rounded-2xl border border-white/10 bg-white/5 px-3 py-2"
                                 >
                                   <div>
-                                    <p className="text-sm text-text">{new Date(entry.created_at).toLocaleString()}</p>
-                                    <p className="text-xs text-muted">{(entry.size_bytes / 1024 / 1024).toFixed(1)} MB</p>
+                                    <p className="text-sm text-text">
+                                      {new Date(entry.created_at).toLocaleString()}
+                                      {entry.kind === "incremental" && <span className="ml-2 text-xs text-muted">incremental</span>}
+                                      {entry.encrypted && <span className="ml-2 text-xs text-muted">encrypted</span>}
+                                      {entry.exists === false && <span className="ml-2 text-xs text-danger">file missing</span>}
+                                    </p>
+                                    <p className="text-xs text-muted">
+                                      {entry.compressed_bytes
+                                        ? `${(entry.compressed_bytes / 1024 / 1024).toFixed(1)} MB of ${(entry.size_bytes / 1024 / 1024).toFixed(1)} MB · ${entry.compression}`
+                                        : `${(entry.size_bytes / 1024 / 1024).toFixed(1)} MB`}
+                                    </p>
                                   </div>
                                   <div className="flex items-center gap-2">
-                                    <SubtleButton onClick={() => handleRestoreBackup(entry)}>Restore</SubtleButton>
+                                    <SubtleButton onClick={() => handleRestoreBackup(entry)} disabled={entry.exists === false}>
+                                      Restore
+                                    </SubtleButton>
                                     <SubtleButton onClick={() => handleDeleteBackup(entry)} className="text-danger">
                                       Delete
                                     </SubtleButton>
@@ -2,26 +2,10 @@ import { useEffect, useMemo, useRef, useState } from "react";
 import { PrimaryButton, SubtleButton } from "./ui/Buttons";
 import { classNames } from "../utils/classNames";
 import type { ConsoleEntry, ConsoleLineType } from "../services/consoleParser";
+import type { AvailableCommand } from "../types";
 import { QuickCommandsPanel } from "./QuickCommandsPanel";
 import { useConsoleHistory } from "../hooks/useConsoleHistory";
 
-const COMMANDS = [
-  "save-all",
-  "list",
-  "stop",
-  "whitelist add",
-  "whitelist remove",
-  "kick",
-  "op",
-  "deop",
-  "gamemode survival",
-  "gamemode creative",
-  "gamemode spectator",
-  "time set day",
-  "difficulty normal",
-  "say"
-];
-
 const ROW_HEIGHT = 20;
 const BUFFER = 20;
 
@@ -36,13 +20,25 @@ const toneByType: Record<ConsoleLineType, string> = {
 };
 
 export function ConsoleView({
+  serverName,
+  running,
   entries,
   players,
-  onSendCommand
+  onSendCommand,
+  loadHistory,
+  suggestCommands,
+  loadCommands,
+  onClearHistory
 }: {
+  serverName: string | null;
+  running: boolean;
   entries: ConsoleEntry[];
   players: string[];
   onSendCommand: (command: string) => void;
+  loadHistory: () => Promise<string[]>;
+  suggestCommands: (prefix: string) => Promise<string[]>;
+  loadCommands: () => Promise<AvailableCommand[]>;
+  onClearHistory: () => Promise<void>;
 }) {
   const [input, setInput] = useState("");
   const [filter, setFilter] = useState<FilterMode>("all");
@@ -50,7 +46,9 @@ export function ConsoleView({
   const [scrollTop, setScrollTop] = useState(0);
   const [viewportHeight, setViewportHeight] = useState(0);
   const containerRef = useRef<HTMLDivElement | null>(null);
-  const { record, handleKeyDown } = useConsoleHistory();
+  const [suggestions, setSuggestions] = useState<string[]>([]);
+  const [availableCommands, setAvailableCommands] = useState<AvailableCommand[]>([]);
+  const { reset, record, handleKeyDown } = useConsoleHistory();
 
   const filtered = useMemo(() => {
     if (filter === "all") return entries;
@@ -65,11 +63,49 @@ export function ConsoleView({
   const topPadding = startIndex * ROW_HEIGHT;
   const bottomPadding = totalHeight - endIndex * ROW_HEIGHT;
 
-  const suggestions = useMemo(() => {
+  useEffect(() => {
+    reset([]);
+    if (!serverName) return;
+    loadHistory()
+      .then(reset)
+      .catch(() => undefined);
+  }, [serverName]);
+
+  useEffect(() => {
+    setAvailableCommands([]);
+    if (!serverName) return;
+    loadCommands()
+      .then(setAvailableCommands)
+      .catch(() => undefined);
+  }, [serverName, running]);
+
+  useEffect(() => {
     const value = input.trim();
-    if (!value) return [];
-    return COMMANDS.filter((command) => command.startsWith(value)).slice(0, 4);
-  }, [input]);
+    if (!value || !serverName) {
+      setSuggestions([]);
+      return;
+    }
+    let cancelled = false;
+    suggestCommands(value)
+      .then((next) => {
+        if (cancelled) return;
+        const known = value.includes(" ")
+          ? []
+          : availableCommands
+              .map((command) => command.name)
+              .filter((name) => name.startsWith(value.replace(/^\//, "")) && !next.includes(name));
+        setSuggestions([...next, ...known].filter((command) => command !== value).slice(0, 4));
+      })
+      .catch(() => undefined);
+    return () => {
+      cancelled = true;
+    };
+  }, [input, serverName, availableCommands]);
+
+  const handleClearHistory = async () => {
+    await onClearHistory();
+    reset([]);
+  };
 
   const helperText = input.trim().startsWith("whitelist ") ? "whitelist add <player>" : null;
 
@@ -136,9 +172,14 @@ export function ConsoleView({
             </SubtleButton>
           ))}
         </div>
-        <SubtleButton onClick={() => setAutoScroll((prev) => !prev)}>
-          {autoScroll ? "Auto-scroll on" : "Auto-scroll off"}
-        </SubtleButton>
+        <div className="flex items-center gap-2">
+          <SubtleButton onClick={handleClearHistory} disabled={!serverName}>
+            Clear history
+          </SubtleButton>
+          <SubtleButton onClick={() => setAutoScroll((prev) => !prev)}>
+            {autoScroll ? "Auto-scroll on" : "Auto-scroll off"}
+          </SubtleButton>
+        </div>
       </div>
 
       <div
@@ -156,7 +197,9 @@ export function ConsoleView({
                   key={entry.id}
                   className={classNames("leading-5", toneByType[entry.type])}
                   style={{ height: ROW_HEIGHT }}
+                  title={entry.source === "logfile" ? "Read from logs/latest.log" : undefined}
                 >
+                  {entry.source === "logfile" && <span className="mr-2 text-muted">[log]</span>}
                   {entry.text}
                 </div>
               ))}
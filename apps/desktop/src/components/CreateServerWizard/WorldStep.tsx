@@ -108,6 +108,11 @@ export function WorldStep({
               {validation.detected_type && (
                 <p>Detected type: {validation.detected_type === "forge" ? "Forge" : "Vanilla"}</p>
               )}
+              {validation.compatibility_message && validation.compatibility !== "ok" && (
+                <p className={validation.compatibility === "downgrade_blocked" ? "text-danger" : "text-amber-200"}>
+                  {validation.compatibility_message}
+                </p>
+              )}
               {validation.detected_type === "forge" && (
                 <p className="text-amber-200">This world may require the same mods to run properly.</p>
               )}
@@ -8,6 +8,7 @@ import {
   sendDiscordWebhook,
   validateDiscordWebhookUrl
 } from "../services/webhookService";
+import { errorMessage } from "../utils/errors";
 
 const TEMPLATE_HELP = "Use {serverName}, {ip}, {port}, {ramPercent}.";
 
@@ -86,7 +87,7 @@ export function DiscordSettings({
       await sendDiscordWebhook(trimmed, `✅ Discord webhook connected for ${serverName}.`);
       onNotify("success", "Webhook test sent.");
     } catch (err) {
-      onNotify("error", String(err));
+      onNotify("error", errorMessage(err));
     } finally {
       setTesting(false);
     }
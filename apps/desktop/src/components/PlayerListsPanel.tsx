@@ -0,0 +1,78 @@
+import { useState } from "react";
+import { SubtleButton } from "./ui/Buttons";
+import type { PlayerImportResult } from "../types";
+import { errorMessage } from "../utils/errors";
+
+type PlayerList = "whitelist" | "ops";
+
+const summarize = (result: PlayerImportResult) => {
+  const parts = [`${result.added.length} added`];
+  if (result.already_listed.length) parts.push(`${result.already_listed.length} already listed`);
+  if (result.failed.length) parts.push(`not found: ${result.failed.join(", ")}`);
+  if (result.via_console) parts.push("sent to the running server");
+  return parts.join(" · ");
+};
+
+export function PlayerListsPanel({
+  importPlayers,
+  exportPlayers
+}: {
+  importPlayers: (list: PlayerList, names: string[]) => Promise<PlayerImportResult>;
+  exportPlayers: (list: PlayerList) => Promise<string[]>;
+}) {
+  const [text, setText] = useState("");
+  const [message, setMessage] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  const names = text
+    .split(/[\s,;]+/)
+    .map((name) => name.trim())
+    .filter(Boolean);
+
+  const run = async (task: () => Promise<string>) => {
+    setBusy(true);
+    setMessage(null);
+    try {
+      setMessage(await task());
+    } catch (err) {
+      setMessage(errorMessage(err));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const add = (list: PlayerList) => run(async () => summarize(await importPlayers(list, names)));
+
+  const copy = (list: PlayerList) =>
+    run(async () => {
+      const players = await exportPlayers(list);
+      await navigator.clipboard?.writeText(players.join("\n"));
+      return `Copied ${players.length} names.`;
+    });
+
+  return (
+    <div className="grid gap-3">
+      <textarea
+        className="min-h-24 w-full rounded-2xl border border-white/10 bg-white/5 px-4 py-2 text-sm text-text focus:border-one/60 focus:outline-none"
+        placeholder="Paste player names, one per line or separated by commas"
+        value={text}
+        onChange={(event) => setText(event.target.value)}
+      />
+      <div className="flex flex-wrap items-center gap-2">
+        <SubtleButton onClick={() => add("whitelist")} disabled={busy || names.length === 0}>
+          Add to whitelist
+        </SubtleButton>
+        <SubtleButton onClick={() => add("ops")} disabled={busy || names.length === 0}>
+          Add as operators
+        </SubtleButton>
+        <SubtleButton onClick={() => copy("whitelist")} disabled={busy}>
+          Copy whitelist
+        </SubtleButton>
+        <SubtleButton onClick={() => copy("ops")} disabled={busy}>
+          Copy operators
+        </SubtleButton>
+      </div>
+      {message && <p className="text-xs text-muted">{message}</p>}
+    </div>
+  );
+}
@@ -0,0 +1,57 @@
+import { useEffect, useState } from "react";
+import { SubtleButton } from "./ui/Buttons";
+import { classNames } from "../utils/classNames";
+import type { ConsoleIssue } from "../types";
+import { errorMessage } from "../utils/errors";
+
+const REFRESH_MS = 30_000;
+
+export function RecentIssuesPanel({
+  serverName,
+  loadIssues
+}: {
+  serverName: string;
+  loadIssues: () => Promise<ConsoleIssue[]>;
+}) {
+  const [issues, setIssues] = useState<ConsoleIssue[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  const refresh = async () => {
+    try {
+      setIssues(await loadIssues());
+      setError(null);
+    } catch (err) {
+      setError(errorMessage(err));
+    }
+  };
+
+  useEffect(() => {
+    setIssues([]);
+    refresh();
+    const timer = window.setInterval(refresh, REFRESH_MS);
+    return () => window.clearInterval(timer);
+  }, [serverName]);
+
+  return (
+    <div className="grid gap-2">
+      {issues.length === 0 && !error && <p className="text-xs text-muted">No warnings or errors since the last start.</p>}
+      {issues.map((issue) => (
+        <div
+          key={`${issue.kind}:${issue.message}`}
+          className="flex items-start justify-between gap-3 rounded-2xl border border-white/10 bg-white/5 px-4 py-2"
+        >
+          <p className={classNames("text-xs", issue.severity === "error" ? "text-danger" : "text-text")}>
+            {issue.message}
+          </p>
+          <span className="shrink-0 text-xs text-muted">
+            {issue.last_hour > 0 ? `${issue.last_hour}× in the last hour` : `${issue.count}×`}
+          </span>
+        </div>
+      ))}
+      {error && <p className="text-xs text-danger">{error}</p>}
+      <div>
+        <SubtleButton onClick={refresh}>Refresh</SubtleButton>
+      </div>
+    </div>
+  );
+}
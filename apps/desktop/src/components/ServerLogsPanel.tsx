@@ -0,0 +1,116 @@
+import { useEffect, useState } from "react";
+import { SubtleButton } from "./ui/Buttons";
+import { classNames } from "../utils/classNames";
+import type { ServerLogFile, ServerLogMatch } from "../types";
+import { errorMessage } from "../utils/errors";
+
+export function ServerLogsPanel({
+  serverName,
+  loadFiles,
+  readLog,
+  searchLogs
+}: {
+  serverName: string;
+  loadFiles: () => Promise<ServerLogFile[]>;
+  readLog: (file: string) => Promise<string[]>;
+  searchLogs: (pattern: string) => Promise<ServerLogMatch[]>;
+}) {
+  const [files, setFiles] = useState<ServerLogFile[]>([]);
+  const [activeFile, setActiveFile] = useState<string | null>(null);
+  const [lines, setLines] = useState<string[]>([]);
+  const [pattern, setPattern] = useState("");
+  const [matches, setMatches] = useState<ServerLogMatch[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [busy, setBusy] = useState(false);
+
+  const run = async (task: () => Promise<void>) => {
+    setBusy(true);
+    setError(null);
+    try {
+      await task();
+    } catch (err) {
+      setError(errorMessage(err));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const refresh = () => run(async () => setFiles(await loadFiles()));
+
+  useEffect(() => {
+    setActiveFile(null);
+    setLines([]);
+    setMatches(null);
+    refresh();
+  }, [serverName]);
+
+  const openFile = (file: string) =>
+    run(async () => {
+      setMatches(null);
+      setActiveFile(file);
+      setLines(await readLog(file));
+    });
+
+  const search = () => {
+    if (!pattern.trim()) return;
+    run(async () => {
+      setActiveFile(null);
+      setMatches(await searchLogs(pattern.trim()));
+    });
+  };
+
+  return (
+    <div className="grid gap-3">
+      <div className="flex flex-wrap items-center gap-2">
+        {files.length === 0 && <p className="text-xs text-muted">No log files yet.</p>}
+        {files.map((file) => (
+          <SubtleButton
+            key={file.name}
+            onClick={() => openFile(file.name)}
+            className={classNames(activeFile === file.name ? "bg-one/20 text-one ring-1 ring-one/40" : "")}
+          >
+            {file.name} · {(file.size_bytes / 1024).toFixed(0)} KB
+          </SubtleButton>
+        ))}
+        <SubtleButton onClick={refresh} disabled={busy}>
+          Refresh
+        </SubtleButton>
+      </div>
+      <div className="flex items-center gap-2">
+        <input
+          className="w-full rounded-2xl border border-white/10 bg-white/5 px-4 py-2 text-sm text-text focus:border-one/60 focus:outline-none"
+          placeholder="Search all logs (regex), e.g. (?i)chest|tnt"
+          value={pattern}
+          onChange={(event) => setPattern(event.target.value)}
+          onKeyDown={(event) => {
+            if (event.key === "Enter") search();
+          }}
+        />
+        <SubtleButton onClick={search} disabled={busy || !pattern.trim()}>
+          Search
+        </SubtleButton>
+      </div>
+      {error && <p className="text-xs text-danger">{error}</p>}
+      {(activeFile || matches) && (
+        <div className="max-h-96 overflow-auto rounded-2xl border border-white/10 bg-black/30 px-4 py-3 font-mono text-xs text-muted">
+          {matches
+            ? matches.length === 0
+              ? "No matches."
+              : matches.map((match) => (
+                  <p key={`${match.file}:${match.line_number}`} className="whitespace-pre-wrap">
+                    <span className="text-one">
+                      {match.file}:{match.line_number}
+                    </span>{" "}
+                    {match.line}
+                  </p>
+                ))
+            : lines.map((line, index) => (
+                <p key={index} className="whitespace-pre-wrap">
+                  {line}
+                </p>
+              ))}
+        </div>
+      )}
+    </div>
+  );
+}
@@ -4,6 +4,10 @@ import type { Difficulty, GameMode, ServerSettings } from "../types";
 import { DIFFICULTY_OPTIONS, GAMEMODE_OPTIONS } from "../constants/serverOptions";
 import { SettingRow } from "./ui/SettingRow";
 
+// Mirrors `percentage_to_sleepers` in the backend.
+const sleepersFor = (percentage: number, maxPlayers: number) =>
+  maxPlayers <= 0 ? 1 : Math.max(1, Math.ceil((Math.min(100, percentage) * maxPlayers) / 100));
+
 export function ServerSettingsFields({
   settings,
   onChange,
@@ -100,26 +104,48 @@ export function ServerSettingsFields({
 
       {(variant === "advanced" || variant === "all") && (
         <>
-          <SettingRow label="Required sleeping players" description="How many players must sleep to skip the night.">
+          <SettingRow
+            label="Sleeping players (%)"
+            description={`Share of online players that must sleep to skip the night (${sleepersFor(
+              settings.sleepPercentage,
+              settings.maxPlayers
+            )} of ${settings.maxPlayers} when full).`}
+          >
             <input
               type="number"
-              min={1}
-              max={10}
-              value={settings.sleepPlayers}
-              onChange={(event) =>
-                update({ sleepPlayers: Math.max(1, Math.min(10, Number(event.target.value) || 1)) })
-              }
+              min={0}
+              max={100}
+              value={settings.sleepPercentage}
+              onChange={(event) => {
+                const sleepPercentage = Math.max(0, Math.min(100, Number(event.target.value) || 0));
+                update({ sleepPercentage, sleepPlayers: sleepersFor(sleepPercentage, settings.maxPlayers) });
+              }}
               className="w-20 rounded-full border border-white/10 bg-white/10 px-3 py-2 text-xs font-semibold text-text focus:border-one/60 focus:outline-none"
             />
           </SettingRow>
           <SettingRow label="View distance" description="How far players can see chunks.">
             <input
               type="number"
-              min={4}
+              min={3}
               max={32}
               value={settings.viewDistance}
+              onChange={(event) => {
+                const viewDistance = Math.max(3, Math.min(32, Number(event.target.value) || 3));
+                update({ viewDistance, simulationDistance: Math.min(settings.simulationDistance, viewDistance) });
+              }}
+              className="w-20 rounded-full border border-white/10 bg-white/10 px-3 py-2 text-xs font-semibold text-text focus:border-one/60 focus:outline-none"
+            />
+          </SettingRow>
+          <SettingRow label="Simulation distance" description="How far from players entities and crops keep ticking.">
+            <input
+              type="number"
+              min={3}
+              max={settings.viewDistance}
+              value={settings.simulationDistance}
               onChange={(event) =>
-                update({ viewDistance: Math.max(4, Math.min(32, Number(event.target.value) || 4)) })
+                update({
+                  simulationDistance: Math.max(3, Math.min(settings.viewDistance, Number(event.target.value) || 3))
+                })
               }
               className="w-20 rounded-full border border-white/10 bg-white/10 px-3 py-2 text-xs font-semibold text-text focus:border-one/60 focus:outline-none"
             />
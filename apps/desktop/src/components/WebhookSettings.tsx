@@ -0,0 +1,126 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import type { WebhookConfig, WebhookEvent } from "../types";
+import { SubtleButton } from "./ui/Buttons";
+import { classNames } from "../utils/classNames";
+import { errorMessage } from "../utils/errors";
+
+const WEBHOOK_EVENTS: { value: WebhookEvent; label: string }[] = [
+  { value: "server_start", label: "Start" },
+  { value: "server_ready", label: "Ready" },
+  { value: "server_stop", label: "Stop" },
+  { value: "server_crash", label: "Crash" },
+  { value: "backup_complete", label: "Backup done" },
+  { value: "backup_failed", label: "Backup failed" },
+  { value: "player_join", label: "Player join" },
+  { value: "player_leave", label: "Player leave" }
+];
+
+export function WebhookSettings({
+  webhooks,
+  disabled,
+  onChange,
+  onNotify
+}: {
+  webhooks: WebhookConfig[];
+  disabled: boolean;
+  onChange: (webhooks: WebhookConfig[]) => void;
+  onNotify: (tone: "success" | "error", message: string) => void;
+}) {
+  const [draftUrl, setDraftUrl] = useState("");
+  const [testing, setTesting] = useState<number | null>(null);
+
+  const update = (index: number, patch: Partial<WebhookConfig>) =>
+    onChange(webhooks.map((webhook, current) => (current === index ? { ...webhook, ...patch } : webhook)));
+
+  const add = () => {
+    const url = draftUrl.trim();
+    if (!url.startsWith("https://")) {
+      onNotify("error", "Webhook URLs must start with https://");
+      return;
+    }
+    const format = /discord(?:app)?\.com\/api\/webhooks\//i.test(url) ? "discord" : "json";
+    onChange([...webhooks, { url, format, events: ["server_crash", "backup_failed"] }]);
+    setDraftUrl("");
+  };
+
+  const test = async (index: number) => {
+    const webhook = webhooks[index];
+    setTesting(index);
+    try {
+      await invoke("test_webhook", { url: webhook.url, format: webhook.format });
+      onNotify("success", "Webhook test sent.");
+    } catch (err) {
+      onNotify("error", errorMessage(err));
+    } finally {
+      setTesting(null);
+    }
+  };
+
+  return (
+    <div className="grid gap-3">
+      {webhooks.map((webhook, index) => (
+        <div key={`${webhook.url}-${index}`} className="grid gap-2 rounded-2xl border border-white/10 bg-white/5 px-3 py-2">
+          <div className="flex flex-wrap items-center gap-2">
+            <p className="min-w-0 flex-1 truncate text-xs text-text">{webhook.url}</p>
+            {(["discord", "json"] as const).map((format) => (
+              <SubtleButton
+                key={format}
+                className={classNames(webhook.format === format ? "bg-one/20 text-one ring-1 ring-one/40" : "")}
+                onClick={() => update(index, { format })}
+                disabled={disabled}
+              >
+                {format === "discord" ? "Discord" : "JSON"}
+              </SubtleButton>
+            ))}
+            <SubtleButton onClick={() => test(index)} disabled={disabled || testing !== null}>
+              {testing === index ? "Sending..." : "Test"}
+            </SubtleButton>
+            <SubtleButton
+              onClick={() => onChange(webhooks.filter((_, current) => current !== index))}
+              disabled={disabled}
+            >
+              Remove
+            </SubtleButton>
+          </div>
+          <div className="flex flex-wrap gap-1.5">
+            {WEBHOOK_EVENTS.map((event) => {
+              const active = webhook.events.includes(event.value);
+              return (
+                <SubtleButton
+                  key={event.value}
+                  className={classNames(active ? "bg-one/20 text-one ring-1 ring-one/40" : "")}
+                  onClick={() =>
+                    update(index, {
+                      events: active
+                        ? webhook.events.filter((value) => value !== event.value)
+                        : [...webhook.events, event.value]
+                    })
+                  }
+                  disabled={disabled}
+                >
+                  {event.label}
+                </SubtleButton>
+              );
+            })}
+          </div>
+        </div>
+      ))}
+      <div className="flex items-center gap-2">
+        <input
+          className="w-full rounded-2xl border border-white/10 bg-white/5 px-3 py-1.5 text-xs text-text focus:border-one/60 focus:outline-none"
+          placeholder="https://discord.com/api/webhooks/..."
+          value={draftUrl}
+          onChange={(event) => setDraftUrl(event.target.value)}
+          onKeyDown={(event) => {
+            if (event.key === "Enter") add();
+          }}
+          disabled={disabled}
+        />
+        <SubtleButton onClick={add} disabled={disabled || !draftUrl.trim()}>
+          Add
+        </SubtleButton>
+      </div>
+    </div>
+  );
+}
@@ -1,4 +1,4 @@
-import type { ServerConfig } from "../../types";
+import type { DeleteServerPreview, ServerConfig } from "../../types";
 import { classNames } from "../../utils/classNames";
 import { SubtleButton } from "../ui/Buttons";
 
@@ -7,6 +7,11 @@ export function DeleteServerModal({
   confirmText,
   deleteMatches,
   deleteBusy,
+  preview,
+  deleteBackups,
+  deleteMeta,
+  onDeleteBackupsChange,
+  onDeleteMetaChange,
   onConfirmTextChange,
   onCancel,
   onDelete
@@ -15,11 +20,17 @@ export function DeleteServerModal({
   confirmText: string;
   deleteMatches: boolean;
   deleteBusy: boolean;
+  preview: DeleteServerPreview | null;
+  deleteBackups: boolean;
+  deleteMeta: boolean;
+  onDeleteBackupsChange: (value: boolean) => void;
+  onDeleteMetaChange: (value: boolean) => void;
   onConfirmTextChange: (value: string) => void;
   onCancel: () => void;
   onDelete: () => void;
 }) {
   if (!target) return null;
+  const megabytes = (bytes: number) => `${(bytes / 1024 / 1024).toFixed(1)} MB`;
 
   return (
     <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/60 px-4">
@@ -41,6 +52,34 @@ export function DeleteServerModal({
             </SubtleButton>
           </div>
         </div>
+        {preview && (
+          <div className="mt-3 grid gap-2 rounded-2xl border border-white/10 bg-white/5 px-4 py-3 text-xs text-muted">
+            <p className="break-all">
+              {preview.linked
+                ? `Linked folder, files stay in place: ${preview.server_dir}`
+                : `${megabytes(preview.size_bytes)} in ${preview.server_dir}`}
+            </p>
+            {preview.running && <p className="text-danger">The server is running and will be stopped.</p>}
+            <label className="flex items-center gap-2">
+              <input
+                type="checkbox"
+                checked={deleteBackups}
+                onChange={(event) => onDeleteBackupsChange(event.target.checked)}
+                disabled={preview.backup_count === 0}
+              />
+              Also delete {preview.backup_count} backup(s) ({megabytes(preview.backups_bytes)}); kept otherwise
+            </label>
+            <label className="flex items-center gap-2">
+              <input
+                type="checkbox"
+                checked={deleteMeta}
+                onChange={(event) => onDeleteMetaChange(event.target.checked)}
+                disabled={!preview.has_meta}
+              />
+              Also delete app settings for this server
+            </label>
+          </div>
+        )}
         <input
           className="mt-4 w-full rounded-2xl border border-white/10 bg-white/5 px-4 py-3 text-sm text-text transition focus:border-danger/60 focus:outline-none"
           placeholder="Type server name"
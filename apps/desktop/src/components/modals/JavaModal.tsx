@@ -1,3 +1,4 @@
+import { useState } from "react";
 import type { JavaStatusResult } from "../../types";
 import { PrimaryButton, SubtleButton } from "../ui/Buttons";
 
@@ -15,9 +16,10 @@ export function JavaModal({
   downloadProgress: number | null;
   busy: boolean;
   onClose: () => void;
-  onDownload: () => void;
+  onDownload: (imageType: "jre" | "jdk") => void;
   onSelect: () => void;
 }) {
+  const [fullJdk, setFullJdk] = useState(false);
   if (!open || !status) return null;
 
   return (
@@ -45,9 +47,28 @@ export function JavaModal({
             <p className="text-xs text-muted">Not detected on your system</p>
           )}
           {status.status === "unsupported" && (
-            <p className="text-xs text-muted">Detected: Java {status.selected_major ?? "unknown"}</p>
+            <p className="text-xs text-muted">
+              Detected: Java {status.selected_major ?? "unknown"}
+              {status.selected_is_64bit === false ? " (32-bit)" : ""}
+              {status.selected_vendor ? ` · ${status.selected_vendor}` : ""}
+            </p>
           )}
+          {status.installed_vendor && (
+            <p className="text-xs text-muted">
+              Installed: {status.installed_vendor} {status.installed_image_type?.toUpperCase() ?? ""}
+            </p>
+          )}
+          {status.message && <p className="text-xs text-danger">{status.message}</p>}
         </div>
+        <label className="mt-4 flex items-center gap-2 text-xs text-muted">
+          <input
+            type="checkbox"
+            checked={fullJdk}
+            disabled={busy}
+            onChange={(event) => setFullJdk(event.target.checked)}
+          />
+          Install the full JDK (needed by mods that compile code at runtime)
+        </label>
         {downloadProgress !== null && (
           <div className="mt-4 grid gap-2">
             <div className="flex items-center justify-between text-xs text-muted">
@@ -63,7 +84,7 @@ export function JavaModal({
           </div>
         )}
         <div className="mt-5 flex flex-col gap-3">
-          <PrimaryButton onClick={onDownload} disabled={busy}>
+          <PrimaryButton onClick={() => onDownload(fullJdk ? "jdk" : "jre")} disabled={busy}>
             {busy ? "Downloading..." : "Download & Install Java (Recommended)"}
           </PrimaryButton>
           <SubtleButton onClick={onSelect} disabled={busy}>
@@ -1,22 +1,38 @@
+import type { InstalledLauncher, LauncherChoice } from "../../types";
 import { PrimaryButton, SubtleButton } from "../ui/Buttons";
 
+const LAUNCHER_LABELS: Record<LauncherChoice, string> = {
+  official: "Official Minecraft Launcher",
+  tlauncher: "TLauncher",
+  prism: "Prism Launcher",
+  multimc: "MultiMC",
+  curseforge: "CurseForge"
+};
+
+const FALLBACK_LAUNCHERS: LauncherChoice[] = ["official", "tlauncher"];
+
 export function LauncherModal({
   open,
   onClose,
   onChoose,
+  launchers,
   launcherPath,
   onPickLauncherPath,
   onClearLauncherPath
 }: {
   open: boolean;
   onClose: () => void;
-  onChoose: (choice: "official" | "tlauncher") => void;
+  onChoose: (choice: LauncherChoice) => void;
+  launchers: InstalledLauncher[];
   launcherPath?: string | null;
   onPickLauncherPath: () => void;
   onClearLauncherPath: () => void;
 }) {
   if (!open) return null;
 
+  const options: { kind: LauncherChoice; version?: string | null }[] =
+    launchers.length > 0 ? launchers : FALLBACK_LAUNCHERS.map((kind) => ({ kind }));
+
   return (
     <div className="fixed inset-0 z-60 flex items-center justify-center bg-black/60 px-6">
       <div className="w-full max-w-sm rounded-3xl border border-white/10 bg-surface p-6 text-sm text-text shadow-soft">
@@ -33,8 +49,15 @@ export function LauncherModal({
           </button>
         </div>
         <div className="mt-5 grid gap-3">
-          <PrimaryButton onClick={() => onChoose("official")}>Official Minecraft Launcher</PrimaryButton>
-          <SubtleButton onClick={() => onChoose("tlauncher")}>TLauncher</SubtleButton>
+          {options.map((option, index) => {
+            const Button = index === 0 ? PrimaryButton : SubtleButton;
+            const label = LAUNCHER_LABELS[option.kind] ?? option.kind;
+            return (
+              <Button key={option.kind} onClick={() => onChoose(option.kind)}>
+                {option.version ? `${label} (${option.version})` : label}
+              </Button>
+            );
+          })}
           <div className="rounded-2xl border border-white/10 bg-white/5 px-4 py-3 text-xs text-muted">
             <p className="text-xs uppercase tracking-[0.2em] text-muted">Custom launcher path</p>
             <p className="mt-2 break-all">{launcherPath ?? "Not set"}</p>
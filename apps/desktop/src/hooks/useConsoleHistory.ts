@@ -5,10 +5,18 @@ export function useConsoleHistory() {
   const indexRef = useRef(-1);
   const draftRef = useRef("");
 
+  const reset = (commands: string[]) => {
+    historyRef.current = [...commands];
+    indexRef.current = -1;
+    draftRef.current = "";
+  };
+
   const record = (command: string) => {
     const trimmed = command.trim();
     if (!trimmed) return;
-    historyRef.current.push(trimmed);
+    if (historyRef.current[historyRef.current.length - 1] !== trimmed) {
+      historyRef.current.push(trimmed);
+    }
     indexRef.current = -1;
     draftRef.current = "";
   };
@@ -43,5 +51,5 @@ export function useConsoleHistory() {
     onChange(next ?? "");
   };
 
-  return { record, handleKeyDown };
+  return { reset, record, handleKeyDown };
 }
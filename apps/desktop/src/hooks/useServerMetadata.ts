@@ -1,4 +1,5 @@
 import { useCallback, useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
 import type { ServerMetadata } from "../types";
 import { detectServerMetadata, getServerMetadata } from "../services/modDetection";
 
@@ -30,5 +31,18 @@ export function useServerMetadata(serverId?: string | null) {
     refresh();
   }, [refresh]);
 
+  // New servers are scanned in the background after create/import.
+  useEffect(() => {
+    if (!serverId) return;
+    const unlisten = listen<{ server_id: string; metadata: ServerMetadata }>("metadata:updated", (event) => {
+      if (event.payload.server_id === serverId) {
+        setMetadata(event.payload.metadata);
+      }
+    });
+    return () => {
+      unlisten.then((stop) => stop());
+    };
+  }, [serverId]);
+
   return { metadata, loading, refresh };
 }
@@ -1,10 +1,14 @@
 export type ConsoleLineType = "info" | "warn" | "error" | "join" | "leave";
 
+/** `logfile` lines were read from logs/latest.log while the process pipes were silent. */
+export type ConsoleSource = "stdout" | "stderr" | "logfile";
+
 export type ConsoleEntry = {
   id: string;
   text: string;
   type: ConsoleLineType;
   timestamp: number;
+  source?: ConsoleSource;
 };
 
 export type PlayerEvent = {
@@ -22,6 +26,11 @@ const JOIN_REGEX = /^(.+?) joined the game/i;
 const LEAVE_REGEX = /^(.+?) left the game/i;
 const LIST_REGEX = /players online: (.*)$/i;
 
+function detectSource(raw: string): ConsoleSource | undefined {
+  const match = raw.match(/^\[(stdout|stderr|logfile)\]/);
+  return match ? (match[1] as ConsoleSource) : undefined;
+}
+
 function normalizeLine(raw: string) {
   return raw.replace(/^\[[^\]]+\]\s*/g, "").trim();
 }
@@ -51,7 +60,8 @@ export function parseConsoleLine(raw: string): ConsoleParseResult {
     id: `${Date.now()}-${Math.random().toString(16).slice(2)}`,
     text,
     type,
-    timestamp: Date.now()
+    timestamp: Date.now(),
+    source: detectSource(raw)
   };
 
   const joinMatch = text.match(JOIN_REGEX);
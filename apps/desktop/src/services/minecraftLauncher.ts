@@ -1,5 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
-import type { LauncherChoice } from "../types";
+import type { InstalledLauncher, LauncherChoice } from "../types";
 
 export async function launchMinecraft(
   choice: LauncherChoice,
@@ -8,3 +8,7 @@ export async function launchMinecraft(
 ) {
   return invoke("launch_minecraft", { choice, version, serverName });
 }
+
+export async function detectInstalledLaunchers() {
+  return invoke<InstalledLauncher[]>("detect_installed_launchers");
+}
@@ -1,5 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
-import type { ServerConfig, ServerMetadata } from "../types";
+import type { ForgeVersions, ServerConfig, ServerMetadata } from "../types";
 
 export type LoaderKind = "vanilla" | "forge" | "fabric";
 
@@ -79,7 +79,7 @@ export function buildFabricVersionId(mcVersion: string, loaderVersion: string):
 }
 
 export async function resolveForgeVersion(mcVersion: string): Promise<string> {
-  const versions = await invoke<string[]>("get_forge_versions");
+  const { versions } = await invoke<ForgeVersions>("get_forge_versions");
   const match = versions.find((value) => value.startsWith(`${mcVersion}-`));
   if (!match) {
     throw new Error("No Forge version found for this Minecraft version");
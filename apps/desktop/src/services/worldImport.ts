@@ -18,18 +18,21 @@ export async function pickWorldFolder(): Promise<string | null> {
 export async function pickWorldZip(): Promise<string | null> {
   const selection = await open({
     multiple: false,
-    filters: [{ name: "World backup", extensions: ["zip"] }]
+    filters: [{ name: "World backup", extensions: ["zip", "mcworld"] }]
   });
   if (!selection || Array.isArray(selection)) return null;
   return selection;
 }
 
-export async function pickAndValidateWorld(kind: WorldSourceKind): Promise<WorldSelectionResult | null> {
+export async function pickAndValidateWorld(
+  kind: WorldSourceKind,
+  serverVersion?: string | null
+): Promise<WorldSelectionResult | null> {
   const selection = kind === "zip" ? await pickWorldZip() : await pickWorldFolder();
   if (!selection) return null;
 
   const sourceKind = getWorldSourceKind(selection);
-  const validation = await validateWorldSource(selection, sourceKind);
+  const validation = await validateWorldSource(selection, sourceKind, serverVersion);
   return { sourcePath: selection, sourceKind, validation };
 }
 
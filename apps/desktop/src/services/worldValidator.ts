@@ -3,7 +3,12 @@ import type { WorldSourceKind, WorldValidationResult } from "../types";
 
 export async function validateWorldSource(
   sourcePath: string,
-  sourceKind: WorldSourceKind
+  sourceKind: WorldSourceKind,
+  serverVersion?: string | null
 ): Promise<WorldValidationResult> {
-  return invoke<WorldValidationResult>("validate_world_source", { sourcePath, sourceKind });
+  return invoke<WorldValidationResult>("validate_world_source", {
+    sourcePath,
+    sourceKind,
+    serverVersion: serverVersion ?? null
+  });
 }
@@ -11,6 +11,16 @@ export type ServerConfig = {
   port: number;
   server_dir: string;
   linked?: boolean;
+  bind_address?: string | null;
+  lan_only?: boolean;
+  description?: string | null;
+  tags?: string[];
+  color?: string | null;
+  sort_index?: number | null;
+  stop_command?: string | null;
+  needs_relocation?: boolean;
+  /** Set by list_servers; false when the folder or its launcher is missing. */
+  healthy?: boolean;
 };
 
 export type ResourceUsage = {
@@ -51,40 +61,72 @@ export type ModSyncStatus = {
 };
 
 export type NetworkInfo = {
-  local_ip: string;
-  public_ip: string;
+  local_ip: string | null;
+  public_ip: string | null;
   port_open: boolean;
+  wan_ip?: string | null;
+  cgnat_suspected: boolean;
+  message?: string | null;
+  lan_only?: boolean;
+  stale?: boolean;
 };
 
 export type ApplyResult = {
   applied: boolean;
   pending_restart: boolean;
+  warnings?: string[];
 };
 
 export type JavaStatusResult = {
   status: "ready" | "missing" | "unsupported";
   required_major: number;
+  message?: string | null;
   selected_path?: string | null;
   selected_major?: number | null;
+  selected_is_64bit?: boolean | null;
+  selected_vendor?: string | null;
   system_path?: string | null;
   system_major?: number | null;
+  system_is_64bit?: boolean | null;
+  system_vendor?: string | null;
   runtime_path?: string | null;
   runtime_major?: number | null;
+  runtime_is_64bit?: boolean | null;
+  runtime_vendor?: string | null;
+  installed_vendor?: string | null;
+  installed_image_type?: "jre" | "jdk" | null;
 };
 
-export type LauncherChoice = "official" | "tlauncher";
+export type LauncherChoice = "official" | "tlauncher" | "prism" | "multimc" | "curseforge";
+
+export type InstalledLauncher = {
+  kind: LauncherChoice;
+  path: string;
+  version?: string | null;
+};
 
 export type ImportAnalysis = {
   suggested_name: string;
   server_type: ServerConfig["server_type"];
   detected_version: string;
   jar_path: string;
+  stop_command: string;
   has_properties: boolean;
   has_world: boolean;
   has_nether: boolean;
   has_end: boolean;
   detected_ram_gb?: number | null;
+  detected_ram_source?: string | null;
   warnings: string[];
+  plugin_count?: number;
+  datapack_count?: number;
+  has_ops?: boolean;
+  has_whitelist?: boolean;
+  has_banned_players?: boolean;
+  motd?: string | null;
+  max_players?: number | null;
+  paper_build?: string | null;
+  world_version?: string | null;
 };
 
 export type WorldImportMode = "generate" | "import";
@@ -106,13 +148,26 @@ export type WorldValidationResult = {
   has_dim_end: boolean;
   detected_version?: string | null;
   detected_type?: "vanilla" | "forge" | null;
+  data_version?: number | null;
+  compatibility?: WorldCompatibility | null;
+  compatibility_message?: string | null;
 };
 
-export type WorldCopyProgress = {
-  server_name: string;
+export type WorldCompatibility = "ok" | "upgrade_needed" | "downgrade_blocked";
+
+export type TransferProgress = {
+  subject: string;
+  transferred_bytes: number;
   total_bytes: number;
-  copied_bytes: number;
   percent: number;
+  bytes_per_second: number;
+  eta_seconds: number | null;
+};
+
+/** @deprecated legacy fields are kept for one release; read TransferProgress fields instead. */
+export type WorldCopyProgress = TransferProgress & {
+  server_name: string;
+  copied_bytes: number;
 };
 
 export type WorldImportPayload = {
@@ -143,8 +198,37 @@ export type BackupEntry = {
   created_at: string;
   size_bytes: number;
   path: string;
+  compression: BackupCompression;
+  compressed_bytes: number;
+  kind: "full" | "incremental";
+  base_id?: string | null;
+  encrypted?: boolean;
+  exists?: boolean;
+  disk_bytes?: number;
+};
+
+export type StorageWarning = {
+  kind: "synced_folder" | "offline_files";
+  path: string;
+  message: string;
+  remediation: string;
 };
 
+export type WorldInfo = {
+  name: string;
+  size_bytes: number;
+  version?: string | null;
+  standard: boolean;
+};
+
+export type BackupReconcileResult = {
+  removed: string[];
+  adopted: string[];
+  unreadable: string[];
+};
+
+export type BackupCompression = "store" | "fast" | "default" | "best";
+
 export type MinecraftClientStatus = {
   running: boolean;
   mcVersion?: string | null;
@@ -174,18 +258,73 @@ export type AppSettings = {
   smart_join_panel_enabled?: boolean;
   notify_on_server_start?: boolean;
   mod_sync_mode?: "ask" | "metadata" | "copy";
+  port_check_url?: string | null;
+  tunnel_secret?: string | null;
+  crash_endpoint?: string | null;
+  update_channel?: "stable" | "beta";
+  file_watching_enabled?: boolean;
+  proxy_url?: string | null;
+  status_server_enabled?: boolean;
+  status_server_port?: number;
+  status_server_lan?: boolean;
+  status_server_token?: string | null;
+  webhooks?: WebhookConfig[];
+};
+
+export type WebhookEvent =
+  | "server_start"
+  | "server_ready"
+  | "server_stop"
+  | "server_crash"
+  | "backup_complete"
+  | "backup_failed"
+  | "player_join"
+  | "player_leave";
+
+export type WebhookConfig = {
+  url: string;
+  events: WebhookEvent[];
+  format: "discord" | "json";
 };
 
 export type UpdateInfo = {
   update_available: boolean;
   latest_version?: string | null;
   download_url?: string | null;
+  asset_size_bytes?: number | null;
+  sha256?: string | null;
+  release_notes?: string | null;
+  published_at?: string | null;
+  prerelease: boolean;
+  channel: "stable" | "beta";
+  stale?: boolean;
+};
+export type DataDirectoryInfo = {
+  data_directory: string;
+  default_directory: string;
+  is_override: boolean;
+  portable: boolean;
+};
+export type DataDirectoryChangeResult = {
+  data_directory: string;
+  migrated: boolean;
+  updated_servers: number;
+  restart_required: boolean;
+  message: string;
+};
+export type DataMigrationProgress = TransferProgress;
+
+/** @deprecated legacy fields are kept for one release; read TransferProgress fields instead. */
+export type UpdateDownloadProgress = TransferProgress & {
+  bytes: number;
+  total: number;
 };
 
 export type CrashReportSummary = {
   file_name: string;
   timestamp: string;
   message: string;
+  submitted_at?: string | null;
 };
 
 export type CrashReport = {
@@ -194,6 +333,8 @@ export type CrashReport = {
   os: string;
   message: string;
   backtrace: string;
+  submission_id?: string | null;
+  submitted_at?: string | null;
 };
 
 export type ServerMeta = {
@@ -209,6 +350,47 @@ export type ServerMeta = {
   discord_template_stop?: string;
   discord_template_crash?: string;
   discord_template_ram?: string;
+  tunnel_address?: string | null;
+  backup_compression?: BackupCompression;
+  incremental_backups?: boolean;
+  full_backup_every?: number;
+  process_priority?: ProcessPriority;
+  properties_authoritative?: boolean;
+  watchdog_enabled?: boolean;
+  watchdog_timeout_seconds?: number;
+  watchdog_action?: WatchdogAction;
+  clean_shutdown?: boolean;
+  stop_grace_seconds?: number;
+  stats?: ServerStats;
+  auto_export?: AutoExportSettings;
+};
+
+export type AutoExportSettings = {
+  enabled: boolean;
+  destination_dir?: string | null;
+  interval_hours: number;
+  keep_last_n: number;
+  last_run_at?: string | null;
+  last_export_at?: string | null;
+};
+
+export type ConnectionAddress = {
+  kind: "tunnel" | "public" | "lan" | "localhost";
+  label: string;
+  address: string;
+  recommended: boolean;
+};
+
+export type ConnectionAddresses = {
+  port: number;
+  custom_port: boolean;
+  addresses: ConnectionAddress[];
+};
+
+export type TunnelStatus = {
+  running: boolean;
+  server_id?: string | null;
+  address?: string | null;
 };
 
 export type ServerMetadata = {
@@ -226,14 +408,342 @@ export type GameMode = "Survival" | "Creative" | "Adventure" | "Spectator";
 
 export type ServerSettings = {
   sleepPlayers: number;
+  sleepPercentage: number;
   difficulty: Difficulty;
   gameMode: GameMode;
   pvp: boolean;
   allowFlight: boolean;
   maxPlayers: number;
   viewDistance: number;
+  simulationDistance: number;
 };
 
 export type VersionEntry = { value: string; label?: string; recommended?: boolean };
 
 export type VersionGroup = { label: string; versions: VersionEntry[] };
+
+export type TemplateJar = {
+  folder: "mods" | "plugins";
+  file_name: string;
+  sha256: string;
+};
+
+export type ServerTemplate = {
+  name: string;
+  server_type: ServerConfig["server_type"];
+  version: string;
+  ram_gb: number;
+  online_mode: boolean;
+  settings: ServerSettings;
+  properties: Record<string, string>;
+  jars: TemplateJar[];
+  created_at: string;
+};
+
+export type TemplateApplyResult = {
+  server: ServerConfig;
+  skipped: string[];
+};
+
+export type BulkBackupSummary = {
+  succeeded: string[];
+  failed: [string, string][];
+};
+
+export type ShutdownProgress = {
+  stage: "tasks" | "backup" | "server" | "done";
+  message: string;
+};
+
+export type ActivityEntry = {
+  timestamp: string;
+  server_id?: string | null;
+  kind: string;
+  message: string;
+};
+
+export type AnalyticsSummary = {
+  enabled: boolean;
+  endpoint?: string | null;
+  total_events: number;
+  events_by_name: Record<string, number>;
+  first_event_at?: string | null;
+  last_event_at?: string | null;
+  queued_events: number;
+  size_bytes: number;
+};
+export type MoveServerResult = {
+  server_dir: string;
+  file_count: number;
+  total_bytes: number;
+};
+export type DeepLinkModpack = {
+  source_url: string;
+  archive_path: string;
+  staged_path: string;
+  detected_pack?: string | null;
+  mod_count: number;
+  manifest?: ModpackManifest | null;
+};
+export type DeepLinkAddServer = {
+  address: string;
+  name?: string | null;
+};
+export type DeepLinkEvent = {
+  event: "deeplink:import_modpack" | "deeplink:add_server" | "deeplink:error";
+  payload: DeepLinkModpack | DeepLinkAddServer | { url: string; message: string };
+};
+export type SingleplayerWorld = {
+  folder_name: string;
+  path: string;
+  display_name: string;
+  size_bytes: number;
+  last_played?: string | null;
+  detected_version?: string | null;
+  detected_type?: string | null;
+  modded: boolean;
+  icon_base64?: string | null;
+  locked: boolean;
+};
+export type ServerFilesChanged = {
+  server_id: string;
+  categories: ("properties" | "mods" | "world" | "configs")[];
+};
+export type DroppedFileResult = {
+  path: string;
+  file_name: string;
+  kind: "mod" | "plugin" | "mods" | "world" | "modpack" | "unknown";
+  valid: boolean;
+  reason?: string | null;
+  mod_ids: string[];
+  loader?: string | null;
+  world?: WorldValidationResult | null;
+  mods?: ModsValidationResult | null;
+  modpack?: ModpackManifest | null;
+};
+
+export type TrimmedRegion = {
+  path: string;
+  region_x: number;
+  region_z: number;
+  size_bytes: number;
+};
+
+export type TrimWorldResult = {
+  dimension: "overworld" | "nether" | "end";
+  keep_radius_blocks: number;
+  dry_run: boolean;
+  regions: TrimmedRegion[];
+  file_count: number;
+  total_bytes: number;
+  backup_id?: string | null;
+  note: string;
+};
+
+export type ProcessPriority = "normal" | "below_normal" | "high";
+export type WatchdogAction = "notify" | "restart" | "kill";
+
+export type RecommendedSettings = {
+  view_distance: number;
+  simulation_distance: number;
+  ram_gb: number;
+  mod_count: number;
+};
+
+export type PendingChangesEvent = {
+  server_id: string;
+  pending_changes: string[];
+};
+
+export type ModsImportResult = {
+  copied: string[];
+  skipped: string[];
+  incompatible: string[];
+};
+
+export type TempUsage = {
+  bytes: number;
+  entries: number;
+};
+
+export type DeleteServerPreview = {
+  server_dir: string;
+  size_bytes: number;
+  linked: boolean;
+  running: boolean;
+  backup_count: number;
+  backups_bytes: number;
+  has_meta: boolean;
+};
+
+export type DeleteServerResult = {
+  removed: string[];
+  freed_bytes: number;
+};
+
+export type ServerLogFile = {
+  name: string;
+  size_bytes: number;
+  modified_at?: string | null;
+  compressed: boolean;
+};
+
+export type ServerLogMatch = {
+  file: string;
+  line_number: number;
+  line: string;
+};
+
+export type ServerStats = {
+  total_starts: number;
+  total_crashes: number;
+  total_uptime_seconds: number;
+  last_startup_seconds?: number | null;
+  last_started_at?: string | null;
+  recent_crashes: string[];
+};
+
+export type ServerHeartbeat = {
+  server_id: string;
+  status: ServerStatus;
+  pid?: number | null;
+  uptime_seconds?: number | null;
+  resource?: ResourceUsage | null;
+  player_count?: number | null;
+  tps?: number | null;
+};
+
+export type AppError = {
+  /** A category (`not_found`, `io`, ...) or a message code such as `java_not_found`. */
+  code: string;
+  /** English fallback text, shown when there is no translation for `code`. */
+  message: string;
+  field?: string;
+  /** One entry per rejected field of a `validation` error. */
+  details?: { field: string; message: string }[];
+  params?: Record<string, string | number | boolean>;
+};
+
+export type UuidMigrationResult = {
+  migrated: { name: string; from: string; to: string }[];
+  files_renamed: number;
+  skipped: string[];
+  backup_id: string | null;
+};
+
+export type StartupPhase = {
+  phase: string;
+  percent: number | null;
+};
+
+export type ServerStatusInfo = {
+  status: ServerStatus;
+  phase: string | null;
+  percent: number | null;
+};
+
+export type PlayerImportResult = {
+  added: string[];
+  already_listed: string[];
+  failed: string[];
+  via_console: boolean;
+};
+
+export type PlayerModeration = {
+  server_id: string;
+  player: string;
+  action: "kick" | "ban";
+  reason: string | null;
+};
+
+export type AddModResult = {
+  manifest: ModpackManifest;
+  warnings: string[];
+};
+
+export type ModDownloadResult = {
+  downloaded: string[];
+  skipped: string[];
+  failed: { id: string; file_name: string; error: string }[];
+  cancelled: boolean;
+};
+
+export type ServerFilesCheck = {
+  jar_valid: boolean | null;
+  missing_libraries: string[];
+  problems: string[];
+};
+
+export type ForgeVersions = {
+  versions: string[];
+  stale: boolean;
+};
+
+export type SupportedVersion = {
+  id: string;
+  experimental: boolean;
+};
+
+export type SupportedVersions = {
+  versions: SupportedVersion[];
+  stale: boolean;
+};
+
+export type ServerDetails = ServerConfig & {
+  meta: ServerMeta;
+  metadata: ServerMetadata | null;
+  status: ServerStatusInfo;
+};
+
+export type SettingDrift = {
+  key: string;
+  settings_value: string;
+  properties_value: string;
+};
+
+export type ConsoleIssue = {
+  kind: string;
+  severity: "warn" | "error";
+  message: string;
+  count: number;
+  last_hour: number;
+  first_seen: string;
+  last_seen: string;
+};
+
+export type SuspectRegion = {
+  path: string;
+  reason: string;
+  restored: boolean;
+};
+
+export type RegionValidation = {
+  checked: number;
+  suspect: SuspectRegion[];
+  restored_from: string | null;
+};
+
+export type CommandHistoryEntry = {
+  command: string;
+  sent_at: string;
+};
+
+export type AvailableCommand = {
+  name: string;
+  /** "minecraft", "server", a plugin name, or "history"/"builtin" when RCON was unavailable. */
+  source: string;
+};
+
+export type AppConfigImport = {
+  imported: string[];
+  skipped: string[];
+  needs_relocation: string[];
+  warnings: string[];
+};
+
+export type ServerHealth = {
+  healthy: boolean;
+  directory_missing: boolean;
+  launcher_missing: boolean;
+  needs_relocation: boolean;
+};
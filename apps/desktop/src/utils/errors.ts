@@ -0,0 +1,30 @@
+import type { AppError } from "../types";
+
+function isAppError(err: unknown): err is AppError {
+  return typeof err === "object" && err !== null && "code" in err && "message" in err;
+}
+
+let translations: Record<string, string> = {};
+
+/** Install message templates keyed by code; `{name}` placeholders are filled from `params`. */
+export function setTranslations(table: Record<string, string>) {
+  translations = table;
+}
+
+function localize(err: AppError): string {
+  const template = translations[err.code];
+  if (!template) return err.message;
+  return Object.entries(err.params ?? {}).reduce(
+    (text, [name, value]) => text.split(`{${name}}`).join(String(value)),
+    template
+  );
+}
+
+/** Text for a rejected `invoke`; commands reject with either a string or an `AppError`. */
+export function errorMessage(err: unknown): string {
+  return isAppError(err) ? localize(err) : String(err);
+}
+
+export function errorCode(err: unknown): AppError["code"] | null {
+  return isAppError(err) ? err.code : null;
+}
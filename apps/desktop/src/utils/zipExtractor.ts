@@ -1,5 +1,6 @@
 import type { WorldSourceKind } from "../types";
 
 export function getWorldSourceKind(path: string): WorldSourceKind {
-  return path.trim().toLowerCase().endsWith(".zip") ? "zip" : "folder";
+  const normalized = path.trim().toLowerCase();
+  return normalized.endsWith(".zip") || normalized.endsWith(".mcworld") ? "zip" : "folder";
 }
@@ -22,6 +22,7 @@ import {
   suggestRamGb,
   type MigrationHost
 } from "../services/migrationService";
+import { errorMessage } from "../utils/errors";
 
 export type MigrationCreatePayload = {
   name: string;
@@ -122,8 +123,8 @@ export function MigrationWizard({
   const canNext = useMemo(() => {
     if (step === 0) return Boolean(host);
     if (step === 1) return Boolean(host);
-    if (step === 2) return Boolean(worldValidation?.valid);
-    if (step === 3) return Boolean(worldValidation?.valid);
+    if (step === 2) return Boolean(worldValidation?.valid) && worldValidation?.compatibility !== "downgrade_blocked";
+    if (step === 3) return Boolean(worldValidation?.valid) && worldValidation?.compatibility !== "downgrade_blocked";
     if (step === 4) return Boolean(serverName.trim() && serverVersion.trim());
     return false;
   }, [step, host, worldValidation, serverName, serverVersion]);
@@ -132,12 +133,12 @@ export function MigrationWizard({
     setWorldBusy(true);
     setWorldError(null);
     try {
-      const result = await pickAndValidateWorld("zip");
+      const result = await pickAndValidateWorld("zip", serverVersion);
       if (!result) return;
       setWorldSourcePath(result.sourcePath);
       setWorldValidation(result.validation);
     } catch (err) {
-      setWorldError(String(err));
+      setWorldError(errorMessage(err));
       setWorldValidation(null);
     } finally {
       setWorldBusy(false);
@@ -152,7 +153,7 @@ export function MigrationWizard({
       if (!result) return;
       setModsValidation(result.validation);
     } catch (err) {
-      setModsError(String(err));
+      setModsError(errorMessage(err));
       setModsValidation(null);
     } finally {
       setModsBusy(false);
@@ -175,7 +176,7 @@ export function MigrationWizard({
       const validation = await validateModsSource(filePath, sourceKind);
       setModsValidation(validation);
     } catch (err) {
-      setModsError(String(err));
+      setModsError(errorMessage(err));
     } finally {
       setModsBusy(false);
     }
@@ -205,7 +206,7 @@ export function MigrationWizard({
         modImport
       });
     } catch (err) {
-      setCreateError(String(err));
+      setCreateError(errorMessage(err));
     } finally {
       setCreating(false);
     }
@@ -323,6 +324,11 @@ export function MigrationWizard({
                     {worldValidation.detected_type && (
                       <p>Detected loader: {worldValidation.detected_type === "forge" ? "Forge" : "Vanilla"}</p>
                     )}
+                    {worldValidation.compatibility_message && worldValidation.compatibility !== "ok" && (
+                      <p className={worldValidation.compatibility === "downgrade_blocked" ? "text-danger" : "text-amber-200"}>
+                        {worldValidation.compatibility_message}
+                      </p>
+                    )}
                   </div>
                 )}
                 {worldError && <p className="mt-2 text-xs text-danger">{worldError}</p>}